@@ -0,0 +1,112 @@
+//! Benchmarks of reading the runtime's clock.
+//!
+//! `std_now` is the baseline every other benchmark is compared against: a
+//! runtime that was not built with a pausable clock should stay within a
+//! single branch of it, both for `Instant::now` and for creating timers.
+
+use bencher::{black_box, Bencher};
+use std::time::Duration;
+use tokio::runtime::{self, Runtime};
+use tokio::time::Instant;
+
+const NUM_CALLS: usize = 1_000;
+
+fn rt() -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+}
+
+fn pausable_rt() -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_time()
+        .pausable_time(false, Duration::from_secs(0))
+        .build()
+        .unwrap()
+}
+
+fn std_now(b: &mut Bencher) {
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(std::time::Instant::now());
+        }
+    });
+}
+
+fn now_outside_runtime(b: &mut Bencher) {
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(Instant::now());
+        }
+    });
+}
+
+fn now(b: &mut Bencher) {
+    let rt = rt();
+    let _enter = rt.enter();
+
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(Instant::now());
+        }
+    });
+}
+
+fn now_pausable(b: &mut Bencher) {
+    let rt = pausable_rt();
+    let _enter = rt.enter();
+
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(Instant::now());
+        }
+    });
+}
+
+fn now_pausable_paused(b: &mut Bencher) {
+    let rt = pausable_rt();
+    let _enter = rt.enter();
+    rt.pause();
+
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(Instant::now());
+        }
+    });
+}
+
+fn sleep_create(b: &mut Bencher) {
+    let rt = rt();
+    let _enter = rt.enter();
+
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(tokio::time::sleep(Duration::from_secs(1)));
+        }
+    });
+}
+
+fn sleep_create_pausable(b: &mut Bencher) {
+    let rt = pausable_rt();
+    let _enter = rt.enter();
+
+    b.iter(|| {
+        for _ in 0..NUM_CALLS {
+            black_box(tokio::time::sleep(Duration::from_secs(1)));
+        }
+    });
+}
+
+bencher::benchmark_group!(
+    time_now,
+    std_now,
+    now_outside_runtime,
+    now,
+    now_pausable,
+    now_pausable_paused,
+    sleep_create,
+    sleep_create_pausable
+);
+
+bencher::benchmark_main!(time_now);
@@ -22,4 +22,10 @@ async fn test_attr_has_args() {}
 #[test]
 async fn test_has_second_test_attr() {}
 
+#[tokio::test(start_paused = "yes")]
+async fn test_start_paused_is_not_a_bool() {}
+
+#[tokio::test(start_paused = true, start_paused = false)]
+async fn test_start_paused_set_twice() {}
+
 fn main() {}
@@ -0,0 +1,9 @@
+use tests_build::tokio;
+
+#[tokio::test(start_paused = true)]
+async fn test_starts_paused() {}
+
+#[tokio::test(flavor = "multi_thread", start_paused = false)]
+async fn test_starts_running() {}
+
+fn main() {}
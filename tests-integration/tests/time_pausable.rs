@@ -0,0 +1,2991 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "pausable", not(feature = "tokio-test")))]
+
+use tokio::runtime::{self, Runtime};
+use tokio::time::error::ClockError;
+use tokio::time::Duration;
+
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+fn pausable_rt() -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(0))
+        .build()
+        .unwrap()
+}
+
+fn rt() -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn handle_pause_and_resume_time() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(true), handle.resume_time());
+    assert_eq!(Ok(false), handle.resume_time());
+}
+
+/// Same as `redundant_pause_returns_false` in tokio/tests/time_pause.rs, which
+/// runs on the `test-util` clock.
+#[test]
+fn redundant_pause_returns_false() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+    assert_eq!(2, handle.pause_depth());
+
+    rt.block_on(async {
+        assert_eq!(Err(ClockError::AlreadyPaused), tokio::time::try_pause());
+    });
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert_eq!(Ok(true), handle.resume_time());
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn handle_pause_time_on_non_pausable_runtime_errors() {
+    let rt = rt();
+    let handle = rt.handle();
+
+    assert_eq!(Err(ClockError::NotPausable), handle.pause_time());
+    assert_eq!(Err(ClockError::NotPausable), handle.resume_time());
+}
+
+#[test]
+fn handle_is_time_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert!(!handle.is_time_paused());
+    handle.pause_time().unwrap();
+    assert!(handle.is_time_paused());
+    handle.resume_time().unwrap();
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn handle_is_time_paused_on_non_pausable_runtime() {
+    let rt = rt();
+
+    assert!(!rt.handle().is_time_paused());
+}
+
+#[test]
+fn builder_start_paused() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+
+    assert!(rt.handle().is_time_paused());
+    assert_eq!(Ok(true), rt.handle().resume_time());
+}
+
+#[test]
+fn builder_start_paused_requires_time_driver() {
+    let res = runtime::Builder::new_current_thread()
+        .start_paused(true)
+        .build();
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn builder_elapsed_time() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .elapsed_time(Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    assert!(rt.elapsed_millis() >= 60_000);
+    assert!(!rt.handle().is_time_paused());
+}
+
+#[test]
+fn builder_elapsed_time_requires_time_driver() {
+    let res = runtime::Builder::new_current_thread()
+        .elapsed_time(Duration::from_secs(60))
+        .build();
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn builder_rejects_an_overflowing_elapsed_time() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .elapsed_time(Duration::new(u64::MAX, 0))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::new(u64::MAX / 2, 0))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn builder_accepts_an_elapsed_time_of_an_hour() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(3600))
+        .build()
+        .unwrap();
+
+    assert!(rt.elapsed_millis() >= 3_600_000);
+    assert!(rt.handle().is_time_paused());
+}
+
+#[test]
+fn pause_scope_resumes_on_drop() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    {
+        let _guard = handle.pause_scope();
+        assert!(handle.is_time_paused());
+    }
+
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn pause_scope_resumes_on_panic() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _guard = handle.pause_scope();
+        panic!("boom");
+    }));
+
+    assert!(res.is_err());
+    assert!(!rt.handle().is_time_paused());
+}
+
+#[test]
+fn pause_scope_leaves_already_paused_clock_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    drop(handle.pause_scope());
+
+    assert!(handle.is_time_paused());
+}
+
+#[test]
+fn wait_for_resume_does_not_block_the_runtime() {
+    use tokio::sync::oneshot;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    handle.pause_time().unwrap();
+
+    rt.block_on(async move {
+        let (tx, rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            tokio::time::wait_for_resume().await;
+            tx.send(()).unwrap();
+        });
+
+        tokio::task::yield_now().await;
+        handle.resume_time().unwrap();
+
+        rx.await.unwrap();
+    });
+}
+
+#[test]
+fn wait_for_pause_does_not_block_the_runtime() {
+    use tokio::sync::oneshot;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let (tx, rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            tokio::time::wait_for_pause().await;
+            tx.send(()).unwrap();
+        });
+
+        tokio::task::yield_now().await;
+        handle.pause_time().unwrap();
+
+        rx.await.unwrap();
+    });
+}
+
+#[test]
+fn pause_events_is_empty_for_non_pausable_runtime() {
+    let rt = rt();
+
+    rt.block_on(async {
+        let mut events = tokio::time::pause_events();
+        assert_eq!(None, events.recv().await);
+    });
+}
+
+#[test]
+fn builder_on_time_pause_and_resume() {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::sync::Arc;
+
+    let pauses = Arc::new(AtomicUsize::new(0));
+    let resumes = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let pauses = pauses.clone();
+        let resumes = resumes.clone();
+
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .pausable_time(false, Duration::from_secs(0))
+            .on_time_pause(move || {
+                pauses.fetch_add(1, SeqCst);
+            })
+            .on_time_resume(move || {
+                resumes.fetch_add(1, SeqCst);
+            })
+            .build()
+            .unwrap()
+    };
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    assert_eq!(1, pauses.load(SeqCst));
+    assert_eq!(0, resumes.load(SeqCst));
+
+    handle.resume_time().unwrap();
+    assert_eq!(1, pauses.load(SeqCst));
+    assert_eq!(1, resumes.load(SeqCst));
+}
+
+#[test]
+fn advance_time_fires_expired_timers() {
+    use tokio::sync::oneshot;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    let start = std::time::Instant::now();
+
+    rt.block_on(async move {
+        let (tx, rx) = oneshot::channel();
+
+        handle.pause_time().unwrap();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            tx.send(()).unwrap();
+        });
+
+        tokio::task::yield_now().await;
+        handle.advance_time(Duration::from_secs(60));
+
+        rx.await.unwrap();
+    });
+
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[test]
+#[should_panic]
+fn advance_time_panics_when_not_paused() {
+    let rt = pausable_rt();
+
+    rt.handle().advance_time(Duration::from_secs(1));
+}
+
+#[test]
+fn paused_duration_is_zero_for_non_pausable_runtime() {
+    let rt = rt();
+
+    rt.block_on(async {
+        assert_eq!(Duration::from_secs(0), tokio::time::paused_duration());
+    });
+}
+
+#[test]
+fn pause_for_resumes_automatically() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    let timer = handle.pause_for(Duration::from_millis(10)).unwrap();
+    assert!(timer.is_some());
+    assert!(handle.is_time_paused());
+
+    // The resume is driven by the runtime.
+    let timeout = Duration::from_secs(5);
+    assert!(rt.block_on(tokio::time::wait_for_resume_timeout(timeout)));
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn pause_for_does_nothing_if_already_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    let timer = handle.pause_for(Duration::from_millis(10)).unwrap();
+    assert!(timer.is_none());
+
+    rt.block_on(tokio::time::sleep_realtime(Duration::from_millis(50)));
+    assert!(handle.is_time_paused());
+    assert_eq!(Ok(true), handle.resume_time());
+}
+
+#[test]
+fn pause_for_is_cancelled_by_manual_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    handle.pause_for(Duration::from_millis(50)).unwrap();
+    handle.resume_time().unwrap();
+    handle.pause_time().unwrap();
+
+    rt.block_on(tokio::time::sleep_realtime(Duration::from_millis(100)));
+    assert!(handle.is_time_paused());
+}
+
+#[test]
+fn pause_for_can_be_cancelled() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    let timer = handle.pause_for(Duration::from_millis(20)).unwrap();
+    timer.unwrap().cancel();
+
+    rt.block_on(tokio::time::sleep_realtime(Duration::from_millis(60)));
+    assert!(handle.is_time_paused());
+}
+
+#[test]
+fn nested_pause_resumes_when_balanced() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(0, handle.pause_depth());
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+    assert_eq!(2, handle.pause_depth());
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert!(handle.is_time_paused());
+    assert_eq!(1, handle.pause_depth());
+
+    assert_eq!(Ok(true), handle.resume_time());
+    assert!(!handle.is_time_paused());
+    assert_eq!(0, handle.pause_depth());
+}
+
+#[test]
+fn run_unpausable_runs_action() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        assert_eq!(42, tokio::time::run_unpausable(|| 42));
+    });
+
+    let rt = rt();
+
+    rt.block_on(async {
+        assert_eq!(42, tokio::time::run_unpausable(|| 42));
+    });
+}
+
+#[test]
+fn run_unpausable_holds_off_pause() {
+    use std::sync::mpsc;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let (tx, rx) = mpsc::channel();
+
+        tokio::time::run_unpausable(|| {
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                handle.pause_time().unwrap();
+                tx.send(()).unwrap();
+            });
+
+            // The pause cannot complete while the action runs.
+            assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+            assert!(!handle.is_time_paused());
+
+            rx
+        })
+        .recv()
+        .unwrap();
+
+        assert!(handle.is_time_paused());
+    });
+}
+
+#[test]
+fn run_if_paused_and_run_if_resumed() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        assert_eq!(None, tokio::time::run_if_paused(|| 1));
+        assert_eq!(Some(2), tokio::time::run_if_resumed(|| 2));
+
+        handle.pause_time().unwrap();
+
+        assert_eq!(Some(1), tokio::time::run_if_paused(|| 1));
+        assert_eq!(None, tokio::time::run_if_resumed(|| 2));
+    });
+
+    let rt = rt();
+
+    rt.block_on(async {
+        assert_eq!(None, tokio::time::run_if_paused(|| 1));
+        assert_eq!(Some(2), tokio::time::run_if_resumed(|| 2));
+    });
+}
+
+#[test]
+fn sleep_does_not_elapse_while_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    let start = std::time::Instant::now();
+
+    rt.block_on(async move {
+        let logical_start = tokio::time::Instant::now();
+        let sleep = tokio::time::sleep(Duration::from_millis(200));
+        tokio::pin!(sleep);
+
+        handle.pause_time().unwrap();
+        let frozen = tokio::time::Instant::now();
+
+        tokio::select! {
+            _ = &mut sleep => panic!("slept while paused"),
+            _ = tokio::time::sleep_realtime(Duration::from_millis(300)) => {}
+        }
+        assert_eq!(frozen, tokio::time::Instant::now());
+
+        handle.resume_time().unwrap();
+        sleep.await;
+
+        assert!(logical_start.elapsed() >= Duration::from_millis(200));
+    });
+
+    // The 300ms pause does not count towards the sleep.
+    assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[test]
+fn interval_does_not_accumulate_ticks_while_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let mut interval = tokio::time::interval(Duration::from_millis(100));
+
+        interval.tick().await;
+        interval.tick().await;
+
+        // Pause across several nominal tick periods.
+        handle.pause_time().unwrap();
+        std::thread::sleep(Duration::from_millis(500));
+        handle.resume_time().unwrap();
+
+        // The remaining ticks are spaced by the period rather than firing in
+        // a burst to catch up.
+        let start = std::time::Instant::now();
+        let first = interval.tick().await;
+        let second = interval.tick().await;
+        let third = interval.tick().await;
+
+        assert_eq!(Duration::from_millis(100), second - first);
+        assert_eq!(Duration::from_millis(100), third - second);
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    });
+}
+
+#[test]
+fn timeout_does_not_expire_while_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let logical_start = tokio::time::Instant::now();
+        let timeout = tokio::time::timeout(Duration::from_millis(200), rx);
+
+        // Pause for longer than the timeout while the inner future is blocked.
+        handle.pause_time().unwrap();
+        let remaining = logical_start + Duration::from_millis(200) - tokio::time::Instant::now();
+        std::thread::sleep(Duration::from_millis(300));
+        handle.resume_time().unwrap();
+
+        // The timeout still has the logical time left at the pause to run.
+        let start = std::time::Instant::now();
+        assert!(timeout.await.is_err());
+
+        assert!(logical_start.elapsed() >= Duration::from_millis(200));
+        assert!(start.elapsed() + Duration::from_millis(10) >= remaining);
+    });
+}
+
+#[test]
+fn sleep_until_preserves_remaining_time_across_pause() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(200);
+        let sleep = tokio::time::sleep_until(deadline);
+        tokio::pin!(sleep);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        handle.pause_time().unwrap();
+        let remaining = deadline - tokio::time::Instant::now();
+        std::thread::sleep(Duration::from_millis(300));
+        handle.resume_time().unwrap();
+
+        // Only the logical time remaining before the deadline is waited for.
+        let start = std::time::Instant::now();
+        sleep.await;
+
+        assert!(tokio::time::Instant::now() >= deadline);
+        assert!(start.elapsed() + Duration::from_millis(10) >= remaining);
+    });
+}
+
+#[test]
+fn is_paused_with_any_load_ordering() {
+    use std::sync::atomic::Ordering;
+
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        assert!(!tokio::time::is_paused());
+        assert!(!tokio::time::is_paused_ordered(Ordering::Relaxed));
+
+        tokio::runtime::Handle::current().pause_time().unwrap();
+
+        assert!(tokio::time::is_paused());
+        assert!(tokio::time::is_paused_ordered(Ordering::Relaxed));
+        assert!(tokio::time::is_paused_ordered(Ordering::Acquire));
+        assert!(tokio::time::is_paused_ordered(Ordering::SeqCst));
+    });
+}
+
+#[test]
+fn is_paused_on_non_pausable_runtime() {
+    use std::sync::atomic::Ordering;
+
+    rt().block_on(async {
+        assert!(!tokio::time::is_paused());
+        assert!(!tokio::time::is_paused_ordered(Ordering::Relaxed));
+    });
+}
+
+#[test]
+fn try_pause_and_try_resume() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        assert_eq!(Ok(true), tokio::time::try_pause());
+        assert_eq!(Err(ClockError::AlreadyPaused), tokio::time::try_pause());
+        assert!(tokio::time::is_paused());
+
+        assert_eq!(Ok(true), tokio::time::try_resume());
+        assert!(!tokio::time::is_paused());
+        assert_eq!(Ok(false), tokio::time::try_resume());
+    });
+}
+
+#[test]
+fn try_pause_and_try_resume_on_non_pausable_runtime() {
+    rt().block_on(async {
+        assert_eq!(Err(ClockError::NotPausable), tokio::time::try_pause());
+        assert_eq!(Err(ClockError::NotPausable), tokio::time::try_resume());
+    });
+}
+
+#[test]
+fn clock_snapshot_is_consistent() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let snapshot = tokio::time::clock_snapshot();
+        assert!(!snapshot.is_paused());
+        assert!(snapshot.elapsed_millis() >= 60_000);
+
+        tokio::runtime::Handle::current().pause_time().unwrap();
+
+        let paused = tokio::time::clock_snapshot();
+        assert!(paused.is_paused());
+        assert_eq!(paused.now(), tokio::time::Instant::now());
+        assert_eq!(
+            paused.elapsed_millis(),
+            tokio::time::clock_snapshot().elapsed_millis()
+        );
+        assert!(paused.elapsed_millis() >= snapshot.elapsed_millis());
+    });
+}
+
+#[test]
+fn clock_snapshot_on_non_pausable_runtime() {
+    rt().block_on(async {
+        let snapshot = tokio::time::clock_snapshot();
+        assert!(!snapshot.is_paused());
+        assert!(snapshot.now() <= tokio::time::Instant::now());
+    });
+}
+
+#[test]
+fn time_scale_accelerates_timers() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(10.0)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let start = std::time::Instant::now();
+        let logical_start = tokio::time::Instant::now();
+
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+
+        assert!(logical_start.elapsed() >= Duration::from_millis(1000));
+        assert!(start.elapsed() < Duration::from_millis(500));
+    });
+}
+
+#[test]
+fn time_scale_of_zero_stops_time() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(0.0)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            tx.send(()).unwrap();
+        });
+
+        let logical_start = tokio::time::Instant::now();
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(1)) => panic!("time moved"),
+            _ = rx => {}
+        }
+
+        assert_eq!(logical_start, tokio::time::Instant::now());
+        assert!(!tokio::runtime::Handle::current().is_time_paused());
+    });
+}
+
+#[test]
+fn time_scale_must_be_non_negative() {
+    let res = runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(-1.0)
+        .build();
+
+    assert!(res.is_err());
+
+    let res = runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(f64::NAN)
+        .build();
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn time_scale_is_bounded() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(1e300)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_scale(1_000_000.0)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn system_now_with_a_large_elapsed_time() {
+    // Some 100,000 years.
+    let elapsed = Duration::from_secs(100_000 * 365 * 24 * 60 * 60);
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, elapsed)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let before = tokio::time::system_now();
+        tokio::time::try_advance(Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        assert_eq!(before + Duration::from_secs(60), tokio::time::system_now());
+    });
+}
+
+#[test]
+fn step_fires_one_timer_at_a_time() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = tokio::time::Instant::now();
+
+        let first = tokio::spawn(tokio::time::sleep(Duration::from_millis(100)));
+        let second = tokio::spawn(tokio::time::sleep(Duration::from_millis(300)));
+        tokio::task::yield_now().await;
+
+        handle.step().await.unwrap();
+        first.await.unwrap();
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100));
+        assert!(elapsed < Duration::from_millis(300));
+        assert!(handle.is_time_paused());
+
+        handle.step().await.unwrap();
+        second.await.unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert!(handle.is_time_paused());
+    });
+}
+
+/// Resumes and pauses the clock of `handle` on another thread, over and
+/// over, until `f` returns.
+fn with_pause_toggled<F: FnOnce()>(handle: runtime::Handle, f: F) {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    let done = Arc::new(AtomicBool::new(false));
+
+    let toggler = {
+        let done = done.clone();
+        std::thread::spawn(move || {
+            while !done.load(SeqCst) {
+                let _ = handle.resume_time();
+                let _ = handle.pause_time();
+            }
+        })
+    };
+
+    f();
+
+    done.store(true, SeqCst);
+    toggler.join().unwrap();
+}
+
+#[test]
+fn frozen_now_stands_still_under_concurrent_pause_and_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    with_pause_toggled(rt.handle().clone(), || {
+        let _enter = handle.enter();
+
+        for _ in 0..10_000 {
+            // Two readings of the same pause see the same frozen instant.
+            let generation = tokio::time::pause_generation();
+            let first = tokio::time::frozen_now();
+            std::thread::yield_now();
+            let second = tokio::time::frozen_now();
+
+            if generation == tokio::time::pause_generation() && first.is_some() {
+                assert_eq!(first, second);
+            }
+
+            // So do readings made while the clock is held paused.
+            tokio::time::run_if_paused(|| {
+                let frozen = tokio::time::Instant::now();
+                std::thread::yield_now();
+
+                assert_eq!(frozen, tokio::time::Instant::now());
+            });
+        }
+    });
+}
+
+#[test]
+fn step_leaves_a_concurrently_resumed_clock_alone() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    with_pause_toggled(rt.handle().clone(), || {
+        rt.block_on(async {
+            let handle = tokio::runtime::Handle::current();
+
+            for _ in 0..1_000 {
+                tokio::spawn(tokio::time::sleep(Duration::from_secs(60)));
+                tokio::task::yield_now().await;
+
+                // Whether the step advanced the clock depends on the race;
+                // either way, the driver must not panic.
+                let _ = handle.step().await;
+            }
+        });
+    });
+}
+
+#[test]
+fn step_requires_a_paused_clock() {
+    pausable_rt().block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        assert_eq!(Err(ClockError::NotPaused), handle.step().await);
+    });
+
+    rt().block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        assert_eq!(Err(ClockError::NotPausable), handle.step().await);
+    });
+}
+
+#[test]
+fn resume_notify_fires_on_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let resumed = tokio::time::resume_notify();
+        let notified = resumed.notified();
+
+        handle.pause_time().unwrap();
+
+        let resumer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            handle.resume_time().unwrap();
+        });
+
+        notified.await;
+        assert!(!tokio::time::is_paused());
+        resumer.join().unwrap();
+    });
+}
+
+#[test]
+fn elapsed_excludes_paused_time() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(60))
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let start = tokio::time::elapsed();
+        assert!(start >= Duration::from_secs(60));
+
+        handle.pause_time().unwrap();
+        let paused = tokio::time::elapsed();
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(paused, tokio::time::elapsed());
+
+        handle.advance_time(Duration::from_micros(1500));
+        assert_eq!(paused + Duration::from_micros(1500), tokio::time::elapsed());
+    });
+}
+
+#[test]
+fn pause_generation_counts_transitions() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = tokio::time::pause_generation();
+
+        handle.pause_time().unwrap();
+        handle.pause_time().unwrap();
+        assert_eq!(start + 1, tokio::time::pause_generation());
+
+        handle.resume_time().unwrap();
+        assert_eq!(start + 1, tokio::time::pause_generation());
+
+        handle.resume_time().unwrap();
+        assert_eq!(start + 2, tokio::time::pause_generation());
+    });
+
+    rt().block_on(async {
+        assert_eq!(0, tokio::time::pause_generation());
+    });
+}
+
+#[test]
+fn block_on_makes_no_timer_progress_while_paused() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    let ticks = Arc::new(AtomicUsize::new(0));
+
+    let start = std::time::Instant::now();
+
+    let resumer = rt.block_on(async {
+        let mut resumer = None;
+
+        for i in 0..20 {
+            if i == 5 {
+                handle.pause_time().unwrap();
+
+                let handle = handle.clone();
+                let ticks = ticks.clone();
+
+                // Report the ticks seen at the end of the pause.
+                resumer = Some(std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(300));
+                    let seen = ticks.load(Ordering::SeqCst);
+                    handle.resume_time().unwrap();
+                    seen
+                }));
+            }
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            ticks.fetch_add(1, Ordering::SeqCst);
+        }
+
+        resumer.unwrap()
+    });
+
+    assert_eq!(5, resumer.join().unwrap());
+
+    // The 300ms pause does not count towards the 200ms of sleeps.
+    assert!(start.elapsed() >= Duration::from_millis(450));
+}
+
+#[test]
+fn wait_for_resume_timeout_elapses_while_paused() {
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+
+    let timeout = Duration::from_millis(20);
+    assert!(!rt.block_on(tokio::time::wait_for_resume_timeout(timeout)));
+
+    assert!(!rt.wait_for_resume_timeout(timeout));
+}
+
+#[test]
+fn wait_for_resume_timeout_returns_on_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    assert!(rt.block_on(tokio::time::wait_for_resume_timeout(Duration::from_secs(0))));
+
+    handle.pause_time().unwrap();
+
+    let resumer = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        handle.resume_time().unwrap();
+    });
+
+    let timeout = Duration::from_secs(10);
+    assert!(rt.block_on(tokio::time::wait_for_resume_timeout(timeout)));
+    resumer.join().unwrap();
+}
+
+#[test]
+fn wait_for_resume_timeout_accepts_an_unbounded_timeout() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    let forever = Duration::new(u64::MAX, 999_999_999);
+
+    handle.pause_time().unwrap();
+
+    let resumer = {
+        let handle = handle.clone();
+
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            handle.resume_time().unwrap();
+        })
+    };
+
+    assert!(rt.block_on(tokio::time::wait_for_resume_timeout(forever)));
+    resumer.join().unwrap();
+
+    handle.pause_time().unwrap();
+
+    let resumer = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        handle.resume_time().unwrap();
+    });
+
+    assert!(rt.wait_for_resume_timeout(forever));
+    resumer.join().unwrap();
+}
+
+#[test]
+fn clock_state_restores_logical_time() {
+    use tokio::time::ClockState;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .elapsed_time(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    rt.handle().pause_time().unwrap();
+    rt.handle().advance_time(Duration::from_secs(5));
+
+    let state = rt.block_on(async { tokio::time::clock_state() });
+    assert!(state.is_paused());
+    assert!(state.elapsed() >= Duration::from_secs(65));
+
+    // Real time passing between capture and restore is not counted.
+    std::thread::sleep(Duration::from_millis(20));
+
+    let restored = runtime::Builder::new_current_thread()
+        .enable_all()
+        .restore_clock(state)
+        .build()
+        .unwrap();
+    assert!(restored.handle().is_time_paused());
+
+    restored.block_on(async {
+        assert_eq!(tokio::time::elapsed(), state.elapsed());
+        assert!(tokio::time::paused_duration() >= state.paused_duration());
+    });
+
+    let rebuilt = ClockState::new(state.elapsed(), state.is_paused(), state.paused_duration());
+    assert_eq!(rebuilt, state);
+}
+
+#[test]
+fn frozen_now_is_stable_while_paused() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        assert_eq!(tokio::time::frozen_now(), None);
+
+        assert_eq!(tokio::time::try_pause(), Ok(true));
+        let frozen = tokio::time::frozen_now().unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(tokio::time::Instant::now(), frozen);
+        assert_eq!(tokio::time::frozen_now(), Some(frozen));
+
+        assert_eq!(tokio::time::try_resume(), Ok(true));
+        assert_eq!(tokio::time::frozen_now(), None);
+    });
+
+    assert_eq!(rt().block_on(async { tokio::time::frozen_now() }), None);
+}
+
+#[test]
+fn instant_arithmetic_saturates_when_mixing_timelines() {
+    use tokio::time::Instant;
+
+    let zero = Duration::from_secs(0);
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+
+    // The paused clock lags behind the wall clock.
+    let wall = Instant::from_std(std::time::Instant::now());
+
+    rt.block_on(async move {
+        let now = Instant::now();
+        assert!(wall > now);
+
+        assert_eq!(now - wall, zero);
+        assert_eq!(now.duration_since(wall), zero);
+        assert_eq!(now.checked_duration_since(wall), None);
+        assert_eq!(wall.elapsed(), zero);
+    });
+
+    // An instant from a clock that was advanced is ahead of a clock restored
+    // from an earlier state.
+    let state = rt.block_on(async { tokio::time::clock_state() });
+    rt.handle().advance_time(Duration::from_secs(3600));
+    let advanced = rt.block_on(async { Instant::now() });
+
+    let restored = runtime::Builder::new_current_thread()
+        .enable_all()
+        .restore_clock(state)
+        .build()
+        .unwrap();
+    restored.handle().resume_time().unwrap();
+
+    restored.block_on(async move {
+        assert_eq!(advanced.elapsed(), zero);
+        assert_eq!(Instant::now() - advanced, zero);
+    });
+}
+
+#[test]
+fn pause_metrics_count_transitions() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+    assert_eq!(handle.pause_metrics(), Default::default());
+
+    for _ in 0..3 {
+        handle.pause_time().unwrap();
+        // Nested pauses are not transitions.
+        handle.pause_time().unwrap();
+        handle.resume_time().unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        handle.resume_time().unwrap();
+    }
+
+    handle.pause_time().unwrap();
+
+    let metrics = handle.pause_metrics();
+    assert_eq!(metrics.pause_count(), 4);
+    assert_eq!(metrics.resume_count(), 3);
+    assert!(metrics.total_paused_duration() >= Duration::from_millis(15));
+    assert!(metrics.total_paused_duration() <= handle.pause_metrics().total_paused_duration());
+}
+
+#[test]
+fn pause_metrics_are_zero_for_non_pausable_runtime() {
+    let metrics = rt().handle().pause_metrics();
+
+    assert_eq!(metrics.pause_count(), 0);
+    assert_eq!(metrics.resume_count(), 0);
+    assert_eq!(metrics.total_paused_duration(), Duration::from_secs(0));
+}
+
+#[test]
+fn on_pause_interrupts_select() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    let pauser = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        handle.pause_time().unwrap();
+    });
+
+    let interrupted = rt.block_on(async {
+        tokio::select! {
+            _ = tokio::time::on_pause() => true,
+            _ = tokio::time::sleep(Duration::from_secs(10)) => false,
+        }
+    });
+
+    assert!(interrupted);
+    pauser.join().unwrap();
+}
+
+#[test]
+fn sleep_realtime_elapses_while_paused() {
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+
+    let start = std::time::Instant::now();
+
+    let watchdog_fired = rt.block_on(async {
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(10)) => false,
+            _ = tokio::time::sleep_realtime(Duration::from_millis(30)) => true,
+        }
+    });
+
+    assert!(watchdog_fired);
+    assert!(start.elapsed() >= Duration::from_millis(30));
+}
+
+#[test]
+fn sleep_ignoring_pause_elapses_while_paused() {
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+
+    let start = std::time::Instant::now();
+
+    let watchdog_fired = rt.block_on(async {
+        let mut watchdog = tokio::time::sleep(Duration::from_millis(30));
+        watchdog.ignore_pause();
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(10)) => false,
+            _ = watchdog => true,
+        }
+    });
+
+    assert!(watchdog_fired);
+    assert!(start.elapsed() >= Duration::from_millis(30));
+}
+
+#[test]
+fn sleep_ignoring_pause_is_not_brought_forward_by_advances() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let mut watchdog = tokio::time::sleep(Duration::from_secs(60));
+        watchdog.ignore_pause();
+        let sleep = tokio::time::sleep(Duration::from_secs(60));
+
+        handle.pause_time().unwrap();
+        handle.advance_time(Duration::from_secs(120));
+
+        assert_eq!(Duration::from_secs(0), sleep.remaining());
+        assert!(!watchdog.is_elapsed());
+        assert!(watchdog.remaining() > Duration::from_secs(59));
+
+        sleep.await;
+        handle.resume_time().unwrap();
+    });
+}
+
+#[test]
+fn sleep_ignoring_pause_keeps_its_remaining_time() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let mut sleep = tokio::time::sleep(Duration::from_millis(40));
+        let deadline = sleep.deadline();
+
+        handle.pause_time().unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+
+        // Time spent paused before pinning does not count.
+        let remaining = sleep.remaining();
+        assert!(remaining > Duration::from_millis(30));
+
+        sleep.ignore_pause();
+        sleep.ignore_pause();
+        assert_eq!(deadline, sleep.deadline());
+        assert!(sleep.remaining() <= remaining);
+
+        let start = std::time::Instant::now();
+        sleep.await;
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert!(tokio::time::is_paused());
+
+        handle.resume_time().unwrap();
+    });
+}
+
+#[test]
+fn sleep_ignoring_pause_stays_pinned_when_reset() {
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let mut sleep = tokio::time::sleep(Duration::from_secs(0));
+        sleep.ignore_pause();
+        assert!(sleep.is_elapsed());
+
+        handle.pause_time().unwrap();
+
+        let deadline = Instant::now() + Duration::from_millis(20);
+        sleep.reset(deadline);
+        assert_eq!(deadline, sleep.deadline());
+        assert!(!sleep.is_elapsed());
+
+        let start = std::time::Instant::now();
+        sleep.await;
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert!(tokio::time::is_paused());
+
+        handle.resume_time().unwrap();
+    });
+}
+
+#[test]
+fn pause_time_from_outside_the_runtime() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    let pauser = std::thread::spawn(move || {
+        assert_eq!(Ok(true), handle.pause_time());
+        handle
+    });
+    let handle = pauser.join().unwrap();
+
+    let frozen = rt.block_on(async {
+        assert!(tokio::time::is_paused());
+
+        let frozen = tokio::time::Instant::now();
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(10)) => panic!("slept while paused"),
+            _ = tokio::time::sleep_realtime(Duration::from_millis(30)) => {}
+        }
+
+        frozen
+    });
+
+    assert_eq!(frozen, rt.block_on(async { tokio::time::Instant::now() }));
+
+    let resumer = std::thread::spawn(move || handle.resume_time());
+    assert_eq!(Ok(true), resumer.join().unwrap());
+
+    rt.block_on(async {
+        assert!(!tokio::time::is_paused());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    });
+}
+
+#[test]
+fn run_unresumable_holds_off_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+    handle.pause_time().unwrap();
+
+    let (frozen, resumer) = rt
+        .block_on(async {
+            tokio::time::run_unresumable(|| {
+                let frozen = tokio::time::Instant::now();
+                let resumer = std::thread::spawn(move || handle.resume_time());
+
+                std::thread::sleep(Duration::from_millis(30));
+                assert!(tokio::time::is_paused());
+                assert_eq!(frozen, tokio::time::Instant::now());
+
+                (frozen, resumer)
+            })
+        })
+        .unwrap();
+
+    assert_eq!(Ok(true), resumer.join().unwrap());
+    assert!(!rt.handle().is_time_paused());
+    assert!(rt.block_on(async { tokio::time::Instant::now() }) >= frozen);
+}
+
+#[test]
+fn run_unresumable_on_non_pausable_runtime_errors() {
+    let rt = rt();
+
+    let result = rt.block_on(async { tokio::time::run_unresumable(|| ()) });
+    assert_eq!(Err(ClockError::NotPausable), result);
+}
+
+#[test]
+fn try_advance_on_non_pausable_runtime_errors() {
+    let rt = rt();
+
+    let result = rt.block_on(tokio::time::try_advance(Duration::from_secs(1)));
+    assert_eq!(Err(ClockError::NotPausable), result);
+}
+
+#[test]
+fn advance_to_next_n_fires_timers_in_order() {
+    use std::sync::{Arc, Mutex};
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let order = Arc::new(Mutex::new(vec![]));
+
+        // A task sleeping repeatedly interleaves with one-off sleeps.
+        let ticker = {
+            let order = order.clone();
+
+            tokio::spawn(async move {
+                for _ in 0..3 {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    order.lock().unwrap().push("tick");
+                }
+            })
+        };
+
+        for &(ms, name) in &[(300, "a"), (100, "b"), (500, "c")] {
+            let order = order.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(ms)).await;
+                order.lock().unwrap().push(name);
+            });
+        }
+        tokio::task::yield_now().await;
+
+        // Fires at 100, 200 and 300.
+        assert_eq!(Ok(3), handle.advance_to_next_n(3).await);
+        tokio::task::yield_now().await;
+        assert_eq!(vec!["b", "tick", "a"], *order.lock().unwrap());
+
+        // Fires at 400, 500 and 600, then runs out of timers.
+        assert_eq!(Ok(3), handle.advance_to_next_n(10).await);
+        ticker.await.unwrap();
+        tokio::task::yield_now().await;
+        assert_eq!(
+            vec!["b", "tick", "a", "tick", "c", "tick"],
+            *order.lock().unwrap()
+        );
+
+        assert_eq!(Ok(0), handle.advance_to_next_n(10).await);
+        assert!(handle.is_time_paused());
+    });
+}
+
+#[test]
+fn advance_to_next_n_requires_a_paused_clock() {
+    pausable_rt().block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        assert_eq!(
+            Err(ClockError::NotPaused),
+            handle.advance_to_next_n(1).await
+        );
+    });
+
+    rt().block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        assert_eq!(
+            Err(ClockError::NotPausable),
+            handle.advance_to_next_n(1).await
+        );
+    });
+}
+
+#[test]
+fn on_timer_fire_traces_each_timer() {
+    use std::sync::{Arc, Mutex};
+    use tokio::time::Instant;
+
+    let fired = Arc::new(Mutex::new(vec![]));
+
+    let rt = {
+        let fired = fired.clone();
+
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .pausable_time(true, Duration::from_secs(0))
+            .on_timer_fire(move |deadline, remaining| {
+                fired.lock().unwrap().push((deadline, remaining));
+            })
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = Instant::now();
+
+        for &ms in &[300, 100, 200] {
+            tokio::spawn(tokio::time::sleep(Duration::from_millis(ms)));
+        }
+        tokio::task::yield_now().await;
+
+        assert_eq!(Ok(3), handle.advance_to_next_n(3).await);
+
+        let fired: Vec<_> = fired
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|&(deadline, remaining)| (deadline - start, remaining))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (Duration::from_millis(100), 2),
+                (Duration::from_millis(200), 1),
+                (Duration::from_millis(300), 0),
+            ],
+            fired
+        );
+    });
+}
+
+#[test]
+fn pause_and_snapshot_on_non_pausable_runtime_errors() {
+    let rt = rt();
+
+    assert_eq!(
+        ClockError::NotPausable,
+        rt.handle().pause_and_snapshot().unwrap_err()
+    );
+}
+
+#[test]
+fn resume_with_advance_on_non_pausable_runtime_errors() {
+    let rt = rt();
+
+    assert_eq!(
+        Err(ClockError::NotPausable),
+        rt.handle().resume_with_advance(Duration::from_secs(1))
+    );
+}
+
+#[test]
+fn handle_pausable_is_none_for_non_pausable_runtimes() {
+    assert!(rt().handle().pausable().is_none());
+    assert!(pausable_rt().handle().pausable().is_some());
+}
+
+#[test]
+fn spawn_blocking_sees_paused_clock() {
+    let rt = pausable_rt();
+
+    let (first, second) = rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+
+        tokio::task::spawn_blocking(|| {
+            let first = tokio::time::Instant::now();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            let second = tokio::time::Instant::now();
+
+            (first, second)
+        })
+        .await
+        .unwrap()
+    });
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn sleep_created_while_paused_starts_counting_on_resume() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+        let frozen = tokio::time::Instant::now();
+
+        let sleep = tokio::time::sleep(Duration::from_millis(200));
+        assert_eq!(frozen + Duration::from_millis(200), sleep.deadline());
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        tokio::time::try_resume().unwrap();
+
+        let resumed = std::time::Instant::now();
+        sleep.await;
+
+        // None of the real time spent paused counted towards the sleep.
+        assert!(resumed.elapsed() >= std::time::Duration::from_millis(190));
+        assert!(tokio::time::Instant::now() - frozen >= Duration::from_millis(200));
+    });
+}
+
+#[test]
+fn clock_kind_reports_pausable_clocks() {
+    use tokio::time::ClockKind;
+
+    assert_eq!(ClockKind::Pausable, pausable_rt().handle().clock_kind());
+    assert_eq!(ClockKind::System, rt().handle().clock_kind());
+}
+
+fn quiesced_rt(builder: &mut runtime::Builder) -> (Runtime, std::sync::Arc<AtomicUsize>) {
+    let quiesced = std::sync::Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let quiesced = quiesced.clone();
+
+        builder
+            .enable_all()
+            .pausable_time(false, Duration::from_secs(0))
+            .on_quiesced_pause(move || {
+                quiesced.fetch_add(1, SeqCst);
+            })
+            .build()
+            .unwrap()
+    };
+
+    (rt, quiesced)
+}
+
+/// Waits for a message sent from another thread after a short real delay,
+/// which parks the runtime in the meantime.
+async fn park_briefly() {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        tx.send(()).unwrap();
+    });
+
+    rx.await.unwrap();
+}
+
+#[test]
+fn on_quiesced_pause_runs_immediately_on_an_idle_runtime() {
+    let (rt, quiesced) = quiesced_rt(&mut runtime::Builder::new_current_thread());
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    assert_eq!(1, quiesced.load(SeqCst));
+
+    // Nested pauses are not new pauses.
+    handle.pause_time().unwrap();
+    handle.resume_time().unwrap();
+    assert_eq!(1, quiesced.load(SeqCst));
+
+    handle.resume_time().unwrap();
+    handle.pause_time().unwrap();
+    assert_eq!(2, quiesced.load(SeqCst));
+}
+
+#[test]
+fn on_quiesced_pause_waits_for_the_scheduler_to_park() {
+    let (rt, quiesced) = quiesced_rt(&mut runtime::Builder::new_current_thread());
+    let observed = quiesced.clone();
+
+    rt.block_on(async move {
+        tokio::time::try_pause().unwrap();
+
+        // This task is still being polled.
+        assert_eq!(0, observed.load(SeqCst));
+
+        park_briefly().await;
+        assert_eq!(1, observed.load(SeqCst));
+
+        // Parking again during the same pause does not run it again.
+        park_briefly().await;
+        assert_eq!(1, observed.load(SeqCst));
+    });
+
+    assert_eq!(1, quiesced.load(SeqCst));
+}
+
+#[test]
+fn on_quiesced_pause_skips_pauses_that_end_before_quiescence() {
+    let (rt, quiesced) = quiesced_rt(&mut runtime::Builder::new_current_thread());
+
+    rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+        tokio::time::try_resume().unwrap();
+
+        park_briefly().await;
+    });
+
+    assert_eq!(0, quiesced.load(SeqCst));
+}
+
+#[test]
+fn on_quiesced_pause_on_an_idle_multi_thread_runtime() {
+    let (rt, quiesced) = quiesced_rt(runtime::Builder::new_multi_thread().worker_threads(2));
+
+    // Let the workers start and run out of work.
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    rt.handle().pause_time().unwrap();
+
+    let start = std::time::Instant::now();
+    while quiesced.load(SeqCst) == 0 {
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    assert_eq!(1, quiesced.load(SeqCst));
+
+    rt.handle().resume_time().unwrap();
+}
+
+#[test]
+fn on_quiesced_pause_cannot_use_a_shared_clock() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(tokio::time::LocalClock::new(false))
+        .on_quiesced_pause(|| {})
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn advance_to_mixes_with_relative_advances() {
+    use tokio::sync::oneshot;
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        handle.pause_time().unwrap();
+        let start = Instant::now();
+
+        let (tx10, mut rx10) = oneshot::channel();
+        let (tx20, mut rx20) = oneshot::channel();
+
+        tokio::spawn(async move {
+            tokio::time::sleep_until(start + Duration::from_secs(10)).await;
+            tx10.send(()).unwrap();
+        });
+        tokio::spawn(async move {
+            tokio::time::sleep_until(start + Duration::from_secs(20)).await;
+            tx20.send(()).unwrap();
+        });
+        tokio::task::yield_now().await;
+
+        handle.advance_time(Duration::from_secs(5));
+        handle.advance_to(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(start + Duration::from_secs(10), Instant::now());
+
+        (&mut rx10).await.unwrap();
+        assert!(rx20.try_recv().is_err());
+
+        // Going back in time is refused and leaves the clock unchanged.
+        assert_eq!(
+            Err(ClockError::InPast),
+            handle.advance_to(start + Duration::from_secs(9))
+        );
+        assert_eq!(start + Duration::from_secs(10), Instant::now());
+
+        handle.advance_time(Duration::from_secs(5));
+        handle.advance_to(start + Duration::from_secs(20)).unwrap();
+        rx20.await.unwrap();
+        assert_eq!(start + Duration::from_secs(20), Instant::now());
+    });
+}
+
+#[test]
+fn advancing_too_far_fails_and_leaves_the_clock_unchanged() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let frozen = tokio::time::Instant::now();
+        let too_far = Duration::new(u64::MAX, 0);
+
+        assert_eq!(
+            Err(ClockError::Overflow),
+            tokio::time::try_advance(too_far).await
+        );
+        assert_eq!(
+            Err(ClockError::Overflow),
+            handle.resume_with_advance(too_far)
+        );
+
+        assert_eq!(frozen, tokio::time::Instant::now());
+        assert_eq!(1, handle.pause_depth());
+    });
+}
+
+#[test]
+fn advance_to_requires_a_paused_clock() {
+    let pausable = pausable_rt();
+    let now = pausable.block_on(async { tokio::time::Instant::now() });
+
+    assert_eq!(
+        Err(ClockError::NotPaused),
+        pausable.handle().advance_to(now)
+    );
+    assert_eq!(Err(ClockError::NotPausable), rt().handle().advance_to(now));
+}
+
+#[test]
+fn frozen_runs_the_future_on_a_non_pausable_runtime() {
+    let rt = rt();
+
+    let output = rt.block_on(tokio::time::frozen(async {
+        assert!(!tokio::time::is_paused());
+        42
+    }));
+
+    assert_eq!(42, output);
+}
+
+#[test]
+fn is_paused_relaxed_under_concurrent_transitions() {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    let rt = pausable_rt();
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = rt.handle().clone();
+            let stop = stop.clone();
+
+            std::thread::spawn(move || {
+                let _enter = handle.enter();
+                let mut reads = 0usize;
+
+                while !stop.load(SeqCst) {
+                    let _ = tokio::time::is_paused_relaxed();
+                    reads += 1;
+                }
+
+                // Observing `stop` orders this after the last resume.
+                assert!(!tokio::time::is_paused_relaxed());
+                reads
+            })
+        })
+        .collect();
+
+    let _enter = rt.enter();
+
+    for _ in 0..1000 {
+        rt.handle().pause_time().unwrap();
+        assert!(tokio::time::is_paused_relaxed());
+
+        rt.handle().resume_time().unwrap();
+        assert!(!tokio::time::is_paused_relaxed());
+    }
+
+    stop.store(true, SeqCst);
+
+    for reader in readers {
+        assert!(reader.join().unwrap() > 0);
+    }
+
+    assert!(!tokio::time::is_paused_relaxed());
+}
+
+#[test]
+fn is_paused_relaxed_is_false_on_a_non_pausable_runtime() {
+    let rt = rt();
+
+    assert!(!rt.block_on(async { tokio::time::is_paused_relaxed() }));
+}
+
+type Warps = std::sync::Arc<std::sync::Mutex<Vec<(Duration, Duration)>>>;
+
+/// Builds a paused runtime recording each warp relative to the first one.
+fn warp_rt() -> (Runtime, Warps) {
+    use std::sync::Mutex;
+
+    let warps = Warps::default();
+    let start = Mutex::new(None);
+
+    let rt = {
+        let warps = warps.clone();
+
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .pausable_time(true, Duration::from_secs(0))
+            .on_time_warp(move |old, new| {
+                let start = *start.lock().unwrap().get_or_insert(old);
+                warps.lock().unwrap().push((old - start, new - start));
+            })
+            .build()
+            .unwrap()
+    };
+
+    (rt, warps)
+}
+
+#[test]
+fn on_time_warp_reports_each_advance() {
+    use tokio::time::Instant;
+
+    let (rt, warps) = warp_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let start = Instant::now();
+
+        handle.advance_time(Duration::from_secs(5));
+        handle.advance_to(start + Duration::from_secs(12)).unwrap();
+
+        // Advances that leave the clock unchanged are not reported.
+        handle.advance_time(Duration::from_secs(0));
+        handle.advance_to(start + Duration::from_secs(12)).unwrap();
+
+        assert_eq!(Ok(true), handle.resume_with_advance(Duration::from_secs(3)));
+    });
+
+    assert_eq!(
+        vec![
+            (Duration::from_secs(0), Duration::from_secs(5)),
+            (Duration::from_secs(5), Duration::from_secs(12)),
+            (Duration::from_secs(12), Duration::from_secs(15)),
+        ],
+        *warps.lock().unwrap()
+    );
+}
+
+#[test]
+fn on_time_warp_reports_steps_to_the_next_timer() {
+    let (rt, warps) = warp_rt();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+
+        tokio::spawn(tokio::time::sleep(Duration::from_millis(100)));
+        tokio::task::yield_now().await;
+
+        assert_eq!(Ok(1), handle.advance_to_next_n(1).await);
+    });
+
+    assert_eq!(
+        vec![(Duration::from_millis(0), Duration::from_millis(100))],
+        *warps.lock().unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "from within an `on_time_warp` callback")]
+fn on_time_warp_cannot_change_the_clock() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .on_time_warp(|_, _| {
+            let _ = tokio::runtime::Handle::current().resume_time();
+        })
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        tokio::runtime::Handle::current().advance_time(Duration::from_secs(1));
+    });
+}
+
+#[test]
+fn on_time_warp_can_change_an_unrelated_clock() {
+    use std::sync::{Arc, Mutex};
+
+    let other = tokio::time::LocalClock::new(false);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+
+    let rt = {
+        let other = other.clone();
+        let changes = changes.clone();
+
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .pausable_time(true, Duration::from_secs(0))
+            .on_time_warp(move |_, _| {
+                let mut changes = changes.lock().unwrap();
+
+                changes.push(other.pause());
+                changes.push(other.resume());
+            })
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        tokio::runtime::Handle::current().advance_time(Duration::from_secs(1));
+    });
+
+    assert_eq!(vec![true, true], *changes.lock().unwrap());
+    assert!(!other.is_paused());
+}
+
+#[test]
+fn on_time_warp_cannot_use_a_shared_clock() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(tokio::time::LocalClock::new(false))
+        .on_time_warp(|_, _| {})
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn clock_panic_policy_error_fails_changes_from_warp_callbacks() {
+    use std::sync::{Arc, Mutex};
+    use tokio::runtime::Handle;
+    use tokio::time::error::PanicPolicy;
+
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    let rt = {
+        let results = results.clone();
+
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .pausable_time(true, Duration::from_secs(0))
+            .clock_panic_policy(PanicPolicy::Error)
+            .on_time_warp(move |_, _| {
+                let handle = Handle::current();
+                let mut results = results.lock().unwrap();
+
+                results.push(handle.pause_time());
+                results.push(handle.resume_time());
+                results.push(tokio::time::try_resume());
+                results.push(handle.resume_with_advance(Duration::from_secs(1)));
+            })
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        Handle::current().advance_time(Duration::from_secs(1));
+    });
+
+    assert_eq!(
+        vec![Err(ClockError::InWarpCallback); 4],
+        *results.lock().unwrap()
+    );
+    assert!(rt.handle().is_time_paused());
+    assert_eq!(1, rt.handle().pause_depth());
+}
+
+#[test]
+#[should_panic(expected = "from within an `on_time_warp` callback")]
+fn clock_panic_policy_error_still_panics_in_infallible_methods() {
+    use tokio::time::error::PanicPolicy;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .clock_panic_policy(PanicPolicy::Error)
+        .on_time_warp(|_, _| {
+            tokio::runtime::Handle::current().advance_time(Duration::from_secs(1));
+        })
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        tokio::runtime::Handle::current().advance_time(Duration::from_secs(1));
+    });
+}
+
+#[test]
+fn clock_panic_policy_cannot_use_a_shared_clock() {
+    use tokio::time::error::PanicPolicy;
+
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(tokio::time::LocalClock::new(false))
+        .clock_panic_policy(PanicPolicy::Error)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+
+    // The default policy is accepted with a shared clock.
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(tokio::time::LocalClock::new(false))
+        .clock_panic_policy(PanicPolicy::Panic)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn time_config_reports_the_pausable_clock_configuration() {
+    let mut builder = runtime::Builder::new_current_thread();
+    assert!(builder.time_config().is_none());
+
+    builder
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(30))
+        .time_scale(2.0);
+
+    let cfg = builder.time_config().unwrap();
+    assert!(cfg.start_paused());
+    assert_eq!(Duration::from_secs(30), cfg.elapsed_time());
+    assert_eq!(Some(2.0), cfg.time_scale());
+    assert_eq!(Duration::from_secs(0), cfg.paused_duration());
+
+    let rt = builder.build().unwrap();
+    assert!(rt.handle().is_time_paused());
+}
+
+#[test]
+fn timers_past_their_deadline_fire_promptly_on_resume() {
+    use std::sync::mpsc;
+
+    let rt = runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(0))
+        .build()
+        .unwrap();
+    let handle = rt.handle();
+
+    let (tx, rx) = mpsc::channel();
+    rt.spawn(async move {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        tx.send(()).unwrap();
+    });
+
+    // Let the timer register and the workers park. Without the clock
+    // waking it, the driver would now sleep for a real minute.
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    handle.pause_time().unwrap();
+    handle.advance_time(Duration::from_secs(60));
+    handle.resume_time().unwrap();
+
+    rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+}
+
+#[test]
+fn next_timer_deadline_jumps_from_timer_to_timer() {
+    use tokio::time::Instant;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = Instant::now();
+        assert_eq!(None, handle.next_timer_deadline());
+
+        let fired = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+
+        for &ms in &[300, 100, 200] {
+            let fired = fired.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(ms)).await;
+                fired.lock().unwrap().push(Instant::now() - start);
+            });
+        }
+        tokio::task::yield_now().await;
+
+        while let Some(deadline) = handle.next_timer_deadline() {
+            handle.advance_to(deadline).unwrap();
+            park_briefly().await;
+        }
+
+        assert_eq!(
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(300),
+            ],
+            *fired.lock().unwrap()
+        );
+    });
+}
+
+#[test]
+fn next_timer_deadline_forgets_dropped_timers() {
+    use tokio::time::Instant;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = Instant::now();
+
+        let sleep = tokio::time::sleep(Duration::from_secs(10));
+        assert_eq!(
+            Some(start + Duration::from_secs(10)),
+            handle.next_timer_deadline()
+        );
+
+        drop(sleep);
+        park_briefly().await;
+        assert_eq!(None, handle.next_timer_deadline());
+    });
+
+    assert_eq!(None, rt().handle().next_timer_deadline());
+}
+
+#[test]
+fn pending_timer_count_tracks_armed_and_cancelled_sleeps() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        assert_eq!(0, handle.pending_timer_count());
+
+        let first = tokio::time::sleep(Duration::from_millis(100));
+        let second = tokio::time::sleep(Duration::from_millis(200));
+        let third = tokio::time::sleep(Duration::from_millis(300));
+        park_briefly().await;
+        assert_eq!(3, handle.pending_timer_count());
+
+        drop(second);
+        park_briefly().await;
+        assert_eq!(2, handle.pending_timer_count());
+
+        handle.advance_time(Duration::from_millis(100));
+        first.await;
+        park_briefly().await;
+        assert_eq!(1, handle.pending_timer_count());
+
+        drop(third);
+        park_briefly().await;
+        assert_eq!(0, handle.pending_timer_count());
+    });
+}
+
+#[test]
+fn pending_timer_count_is_zero_without_the_time_driver() {
+    let rt = runtime::Builder::new_current_thread().build().unwrap();
+    assert_eq!(0, rt.handle().pending_timer_count());
+}
+
+#[test]
+fn interval_at_schedule_freezes_while_paused() {
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        let start = Instant::now() + Duration::from_secs(1);
+        let mut interval = tokio::time::interval_at(start, Duration::from_millis(500));
+
+        handle.pause_time().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(Instant::now() + Duration::from_millis(900) < start);
+        handle.resume_time().unwrap();
+
+        let resumed = std::time::Instant::now();
+        assert_eq!(start, interval.tick().await);
+        assert!(Instant::now() >= start);
+
+        // The real time spent paused was not counted towards the start.
+        assert!(resumed.elapsed() >= std::time::Duration::from_millis(900));
+
+        assert_eq!(start + Duration::from_millis(500), interval.tick().await);
+    });
+}
+
+#[test]
+fn shared_clock_freezes_timers_of_every_runtime() {
+    use std::sync::mpsc;
+    use tokio::time::{Instant, LocalClock};
+
+    let clock = LocalClock::new(false);
+
+    let build = || {
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .clock(clock.clone())
+            .build()
+            .unwrap()
+    };
+
+    let first = build();
+    let second = build();
+    let first_handle = first.handle().clone();
+    let second_handle = second.handle().clone();
+
+    assert_eq!(Ok(true), first_handle.pause_time());
+    let frozen = clock.now();
+
+    let (tx, rx) = mpsc::channel();
+    let threads: Vec<_> = vec![first, second]
+        .into_iter()
+        .map(|rt| {
+            let tx = tx.clone();
+
+            std::thread::spawn(move || {
+                rt.block_on(async {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    tx.send(Instant::now()).unwrap();
+                });
+            })
+        })
+        .collect();
+
+    // Neither runtime's timer fires while the shared clock is paused.
+    assert!(rx
+        .recv_timeout(std::time::Duration::from_millis(300))
+        .is_err());
+
+    // Advancing through the other runtime fires both timers.
+    second_handle.advance_time(Duration::from_millis(100));
+
+    for _ in 0..2 {
+        let fired = rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(frozen + Duration::from_millis(100), fired);
+    }
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(Ok(true), second_handle.resume_time());
+}
+
+#[test]
+fn drain_timers_fires_chained_timers() {
+    use std::sync::{Arc, Mutex};
+    use tokio::time::Instant;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = tokio::runtime::Handle::current();
+        let start = Instant::now();
+        let fired = Arc::new(Mutex::new(vec![]));
+
+        // Each timer schedules the next one once it fires.
+        {
+            let fired = fired.clone();
+
+            tokio::spawn(async move {
+                for &ms in &[100, 200, 300] {
+                    tokio::time::sleep(Duration::from_millis(ms)).await;
+                    fired.lock().unwrap().push(Instant::now() - start);
+                }
+            });
+        }
+        tokio::spawn(tokio::time::sleep(Duration::from_millis(250)));
+        tokio::task::yield_now().await;
+
+        let end = handle.drain_timers().await.unwrap();
+
+        assert_eq!(start + Duration::from_millis(600), end);
+        assert_eq!(
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+                Duration::from_millis(600),
+            ],
+            *fired.lock().unwrap()
+        );
+        assert_eq!(None, handle.next_timer_deadline());
+        assert!(handle.is_time_paused());
+    });
+}
+
+#[test]
+fn drain_timers_requires_a_paused_clock() {
+    let pausable = pausable_rt();
+    let handle = pausable.handle().clone();
+
+    pausable.block_on(async move {
+        assert_eq!(Err(ClockError::NotPaused), handle.drain_timers().await);
+    });
+
+    let rt = rt();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async move {
+        assert_eq!(Err(ClockError::NotPausable), handle.drain_timers().await);
+    });
+}
+
+#[test]
+fn resume_time_measured_reports_the_real_time_spent_paused() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+    let paused_duration = || rt.block_on(async { tokio::time::paused_duration() });
+
+    handle.pause_time().unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    let frozen_for = handle.resume_time_measured().unwrap().unwrap();
+
+    assert!(frozen_for >= Duration::from_millis(20));
+    assert_eq!(frozen_for, paused_duration());
+
+    // Only the pause that just ended is reported.
+    handle.pause_time().unwrap();
+    let frozen_again_for = handle.resume_time_measured().unwrap().unwrap();
+
+    assert!(frozen_again_for < frozen_for);
+    assert_eq!(frozen_for + frozen_again_for, paused_duration());
+}
+
+#[test]
+fn resume_time_measured_spans_nested_pauses() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    handle.pause_time().unwrap();
+
+    assert_eq!(Ok(None), handle.resume_time_measured());
+    assert!(handle.is_time_paused());
+
+    let frozen_for = handle.resume_time_measured().unwrap().unwrap();
+    assert!(frozen_for >= Duration::from_millis(20));
+
+    assert_eq!(Ok(None), handle.resume_time_measured());
+}
+
+#[test]
+fn resume_time_measured_requires_a_pausable_clock() {
+    let rt = rt();
+
+    assert_eq!(
+        Err(ClockError::NotPausable),
+        rt.handle().resume_time_measured()
+    );
+}
+
+#[test]
+fn pausable_handle_resume_measured() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .build_pausable()
+        .unwrap();
+    let handle = rt.handle();
+
+    assert_eq!(None, handle.resume_measured());
+
+    handle.pause();
+    std::thread::sleep(Duration::from_millis(10));
+
+    assert!(handle.resume_measured().unwrap() >= Duration::from_millis(10));
+}
+
+#[test]
+fn run_until_idle_lets_tasks_and_timers_settle_on_a_paused_clock() {
+    use std::sync::{Arc, Mutex};
+    use tokio::time::{self, Instant};
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let start = Instant::now();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        {
+            let log = log.clone();
+            tokio::spawn(async move {
+                while let Some(secs) = rx.recv().await {
+                    time::sleep(Duration::from_secs(secs)).await;
+                    log.lock().unwrap().push(start.elapsed());
+                }
+            });
+        }
+
+        tokio::spawn(async move {
+            for secs in 1..=3 {
+                tokio::task::yield_now().await;
+                tx.send(secs).unwrap();
+            }
+        });
+
+        time::run_until_idle().await;
+
+        assert_eq!(
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(3),
+                Duration::from_secs(6),
+            ],
+            *log.lock().unwrap()
+        );
+        assert_eq!(start + Duration::from_secs(6), Instant::now());
+        assert!(time::is_paused());
+
+        // Nothing is left to settle.
+        time::run_until_idle().await;
+        assert_eq!(start + Duration::from_secs(6), Instant::now());
+    });
+}
+
+#[test]
+fn run_until_idle_waits_for_ready_tasks_but_not_for_future_timers() {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        let done = Arc::new(AtomicBool::new(false));
+
+        {
+            let done = done.clone();
+            tokio::spawn(async move {
+                for _ in 0..100 {
+                    tokio::task::yield_now().await;
+                }
+                done.store(true, SeqCst);
+            });
+        }
+
+        let slept = Arc::new(AtomicBool::new(false));
+
+        {
+            let slept = slept.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                slept.store(true, SeqCst);
+            });
+        }
+
+        let start = std::time::Instant::now();
+        tokio::time::run_until_idle().await;
+
+        // The running clock is not advanced to the sleep's deadline.
+        assert!(done.load(SeqCst));
+        assert!(!slept.load(SeqCst));
+        assert!(start.elapsed() < Duration::from_secs(30));
+    });
+}
+
+#[test]
+fn run_until_idle_leaves_a_concurrently_resumed_clock_alone() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+
+    with_pause_toggled(rt.handle().clone(), || {
+        rt.block_on(async {
+            for _ in 0..1_000 {
+                tokio::spawn(tokio::time::sleep(Duration::from_secs(60)));
+                tokio::task::yield_now().await;
+
+                // The clock may be resumed while the idle point is pending;
+                // the driver must then leave it alone rather than panic.
+                tokio::time::run_until_idle().await;
+            }
+        });
+    });
+}
+
+#[test]
+fn shutdown_resumes_the_clock_and_cancels_sleeping_tasks() {
+    use tokio::task::CancelReason;
+
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+
+    let task = rt.spawn(tokio::time::sleep(Duration::from_secs(1)));
+    rt.block_on(tokio::task::yield_now());
+
+    drop(rt);
+
+    let err = futures::executor::block_on(task).unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(Some(CancelReason::RuntimeShutdown), err.cancel_reason());
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn shutdown_does_not_wait_forever_on_blocking_tasks_waiting_for_a_resume() {
+    let rt = pausable_rt();
+    let handle = rt.handle().clone();
+
+    assert_eq!(Ok(true), handle.pause_time());
+
+    let (started_tx, started_rx) = std::sync::mpsc::channel();
+    let task = rt.spawn_blocking(move || {
+        started_tx.send(()).unwrap();
+        futures::executor::block_on(tokio::time::wait_for_resume());
+    });
+
+    started_rx.recv().unwrap();
+    drop(rt);
+
+    futures::executor::block_on(task).unwrap();
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn shutdown_leaves_a_shared_clock_paused() {
+    let clock = tokio::time::LocalClock::new(false);
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+
+    clock.pause();
+    drop(rt);
+
+    assert!(clock.is_paused());
+    clock.resume();
+}
+
+fn pause_scope_rt(scope: tokio::time::PauseScope) -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(0))
+        .pause_scope(scope)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn future_timers_scope_lets_armed_timers_run_through_a_pause() {
+    let rt = pause_scope_rt(tokio::time::PauseScope::FutureTimers);
+    let handle = rt.handle().clone();
+
+    rt.block_on(async {
+        let armed = tokio::time::sleep(Duration::from_millis(50));
+        tokio::task::yield_now().await;
+
+        let start = std::time::Instant::now();
+        assert_eq!(Ok(true), handle.pause_time());
+
+        let frozen = tokio::time::sleep(Duration::from_millis(50));
+        tokio::pin!(frozen);
+
+        armed.await;
+        assert!(start.elapsed() >= std::time::Duration::from_millis(40));
+        assert!(handle.is_time_paused());
+
+        park_briefly().await;
+        assert!(!frozen.is_elapsed());
+        assert_eq!(1, handle.pending_timer_count());
+
+        assert_eq!(Ok(true), handle.resume_time());
+        frozen.await;
+    });
+}
+
+#[test]
+fn all_timers_scope_freezes_armed_timers() {
+    let rt = pause_scope_rt(tokio::time::PauseScope::AllTimers);
+    let handle = rt.handle().clone();
+
+    rt.block_on(async {
+        let armed = tokio::time::sleep(Duration::from_millis(50));
+        tokio::pin!(armed);
+        tokio::task::yield_now().await;
+
+        assert_eq!(Ok(true), handle.pause_time());
+
+        for _ in 0..3 {
+            park_briefly().await;
+        }
+        assert!(!armed.is_elapsed());
+
+        assert_eq!(Ok(true), handle.resume_time());
+        armed.await;
+    });
+}
+
+#[test]
+fn future_timers_scope_freezes_timers_reset_during_a_pause() {
+    use tokio::time::Instant;
+
+    let rt = pause_scope_rt(tokio::time::PauseScope::FutureTimers);
+    let handle = rt.handle().clone();
+
+    rt.block_on(async {
+        let armed = tokio::time::sleep(Duration::from_millis(50));
+        tokio::pin!(armed);
+        tokio::task::yield_now().await;
+
+        assert_eq!(Ok(true), handle.pause_time());
+        tokio::task::yield_now().await;
+
+        armed
+            .as_mut()
+            .reset(Instant::now() + Duration::from_millis(50));
+
+        for _ in 0..3 {
+            park_briefly().await;
+        }
+        assert!(!armed.is_elapsed());
+
+        assert_eq!(Ok(true), handle.resume_time());
+        armed.await;
+    });
+}
+
+#[test]
+fn timers_sharing_a_deadline_fire_in_registration_order() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    let order = rt.block_on(async {
+        let order = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let sleeps: Vec<_> = (0..3)
+            .map(|_| tokio::time::sleep(Duration::from_millis(10)))
+            .collect();
+
+        // Spawn in the reverse order, so that only the registration order
+        // of the timers can explain the order of completion.
+        for (i, sleep) in sleeps.into_iter().enumerate().rev() {
+            let order = order.clone();
+
+            tokio::spawn(async move {
+                sleep.await;
+                order.lock().unwrap().push(i);
+            });
+        }
+        tokio::task::yield_now().await;
+
+        handle.advance_time(Duration::from_millis(10));
+        park_briefly().await;
+
+        let order = order.lock().unwrap().clone();
+        order
+    });
+
+    assert_eq!(vec![0, 1, 2], order);
+}
+
+#[test]
+fn now_never_goes_backwards_while_advanced_and_resumed_concurrently() {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    use tokio::time::Instant;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(true, Duration::from_secs(0))
+        .build()
+        .unwrap();
+    let done = Arc::new(AtomicBool::new(false));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = rt.handle().clone();
+            let done = done.clone();
+
+            std::thread::spawn(move || {
+                let _enter = handle.enter();
+                let mut last = Instant::now();
+
+                while !done.load(SeqCst) {
+                    let now = Instant::now();
+                    assert!(now >= last, "{:?} went back to {:?}", last, now);
+                    last = now;
+                }
+            })
+        })
+        .collect();
+
+    let handle = rt.handle();
+
+    for _ in 0..10_000 {
+        handle.advance_time(Duration::from_micros(1));
+        assert_eq!(Ok(true), handle.resume_time());
+        assert_eq!(Ok(true), handle.pause_time());
+    }
+
+    done.store(true, SeqCst);
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+fn sleep_until_advance_completes_immediately_on_a_running_clock() {
+    let rt = pausable_rt();
+
+    rt.block_on(tokio::time::sleep_until_advance());
+}
+
+#[test]
+fn sleep_until_advance_wakes_every_sleeper_on_the_next_advance() {
+    use std::sync::Arc;
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+    let woken = Arc::new(AtomicUsize::new(0));
+
+    rt.block_on(async {
+        for _ in 0..3 {
+            let woken = woken.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep_until_advance().await;
+                woken.fetch_add(1, SeqCst);
+            });
+        }
+
+        park_briefly().await;
+        assert_eq!(0, woken.load(SeqCst));
+
+        // Advancing by zero leaves time where it is.
+        handle.advance_time(Duration::from_secs(0));
+        park_briefly().await;
+        assert_eq!(0, woken.load(SeqCst));
+
+        handle.advance_time(Duration::from_millis(1));
+        park_briefly().await;
+        assert_eq!(3, woken.load(SeqCst));
+    });
+}
+
+#[test]
+fn sleep_until_advance_completes_when_the_clock_resumes() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    let task = rt.spawn(tokio::time::sleep_until_advance());
+    rt.block_on(park_briefly());
+
+    assert_eq!(Ok(true), handle.resume_time());
+    rt.block_on(task).unwrap();
+}
+
+/// Time source that only moves when stepped, counting its pauses and resumes.
+#[derive(Debug)]
+struct SteppedSource {
+    now: std::sync::Mutex<std::time::Instant>,
+    pauses: AtomicUsize,
+    resumes: AtomicUsize,
+}
+
+impl SteppedSource {
+    fn new() -> std::sync::Arc<SteppedSource> {
+        std::sync::Arc::new(SteppedSource {
+            now: std::sync::Mutex::new(std::time::Instant::now()),
+            pauses: AtomicUsize::new(0),
+            resumes: AtomicUsize::new(0),
+        })
+    }
+
+    fn step(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl tokio::time::TimeSource for SteppedSource {
+    fn now(&self) -> std::time::Instant {
+        *self.now.lock().unwrap()
+    }
+
+    fn pause(&self) {
+        self.pauses.fetch_add(1, SeqCst);
+    }
+
+    fn resume(&self) {
+        self.resumes.fetch_add(1, SeqCst);
+    }
+}
+
+fn time_source_rt(source: &std::sync::Arc<SteppedSource>) -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .time_source(Box::new(source.clone()))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn time_source_drives_the_clock() {
+    let source = SteppedSource::new();
+    let rt = time_source_rt(&source);
+    let handle = rt.handle();
+
+    let start = rt.block_on(async { tokio::time::Instant::now() });
+    source.step(Duration::from_secs(5));
+    let end = rt.block_on(async { tokio::time::Instant::now() });
+    assert_eq!(Duration::from_secs(5), end - start);
+
+    // The source is only told about transitions, not nested pauses.
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+    assert_eq!(1, source.pauses.load(SeqCst));
+
+    handle.advance_time(Duration::from_secs(1));
+    let advanced = rt.block_on(async { tokio::time::Instant::now() });
+    assert_eq!(Duration::from_secs(6), advanced - start);
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert_eq!(Ok(true), handle.resume_time());
+    assert_eq!(1, source.resumes.load(SeqCst));
+}
+
+#[test]
+fn time_source_fires_timers_once_it_reaches_their_deadline() {
+    let source = SteppedSource::new();
+    let rt = time_source_rt(&source);
+    let fired = std::sync::Arc::new(AtomicUsize::new(0));
+
+    rt.block_on(async {
+        let task = {
+            let fired = fired.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                fired.fetch_add(1, SeqCst);
+            })
+        };
+
+        tokio::time::sleep_realtime(Duration::from_millis(50)).await;
+        assert_eq!(0, fired.load(SeqCst));
+
+        source.step(Duration::from_millis(10));
+        task.await.unwrap();
+        assert_eq!(1, fired.load(SeqCst));
+    });
+}
+
+#[test]
+fn time_source_starts_paused() {
+    let source = SteppedSource::new();
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .time_source(Box::new(source.clone()))
+        .build()
+        .unwrap();
+
+    assert_eq!(1, source.pauses.load(SeqCst));
+    assert_eq!(Ok(true), rt.handle().resume_time());
+    assert_eq!(1, source.resumes.load(SeqCst));
+}
+
+#[test]
+fn time_source_rejects_an_elapsed_time() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .elapsed_time(Duration::from_secs(60))
+        .time_source(Box::new(tokio::time::SystemTimeSource::new()))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
@@ -0,0 +1,93 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "pausable-tracing", not(feature = "tokio-test")))]
+
+use tokio::runtime;
+use tokio::time::Duration;
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the fields of the events emitted for clock transitions.
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<Vec<(String, String)>>>>,
+}
+
+struct Fields<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{:?}", value)));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target() == "tokio::time::clock" {
+            let mut fields = vec![];
+            event.record(&mut Fields(&mut fields));
+            self.events.lock().unwrap().push(fields);
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn field<'a>(fields: &'a [(String, String)], name: &str) -> &'a str {
+    &fields.iter().find(|(field, _)| field == name).unwrap().1
+}
+
+#[test]
+fn pause_and_resume_emit_events() {
+    let recorder = Recorder::default();
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(0))
+        .build()
+        .unwrap();
+    let handle = rt.handle();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        assert_eq!(Ok(true), handle.pause_time());
+        assert_eq!(Ok(true), handle.resume_time());
+
+        // Nested pauses and resumes are not transitions.
+        assert_eq!(Ok(true), handle.pause_time());
+        assert_eq!(Ok(false), handle.pause_time());
+        assert_eq!(Ok(false), handle.resume_time());
+        assert_eq!(Ok(true), handle.resume_time());
+    });
+
+    let events = recorder.events.lock().unwrap();
+    assert_eq!(4, events.len());
+
+    let paused: Vec<_> = events.iter().map(|e| field(e, "paused")).collect();
+    assert_eq!(vec!["true", "false", "true", "false"], paused);
+
+    assert_eq!("clock paused", field(&events[0], "message"));
+    assert_eq!("clock resumed", field(&events[1], "message"));
+
+    for event in events.iter() {
+        field(event, "now");
+        field(event, "paused_duration");
+    }
+}
@@ -217,6 +217,22 @@ pub fn main_rt(args: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ### Configure the runtime to start with time paused
+///
+/// ```no_run
+/// #[tokio::test(start_paused = true)]
+/// async fn my_test() {
+///     assert!(tokio::time::is_paused());
+/// }
+/// ```
+///
+/// The runtime is built as if by `Builder::start_paused`, so its time
+/// starts paused and the test can resume and pause it with
+/// `Handle::resume_time` and `Handle::pause_time`. Which clock that is
+/// depends on tokio's features: with `test-util` enabled it is the
+/// `test-util` clock, as for `tokio::time::pause`; otherwise it is the
+/// pausable clock that also runs outside of tests.
+///
 /// ### NOTE:
 ///
 /// If you rename the tokio crate in your dependencies this macro
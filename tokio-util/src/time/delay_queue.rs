@@ -50,6 +50,13 @@ use std::task::{self, Poll};
 /// for items configured to expire the same instant. Also note that delays are
 /// rounded to the closest millisecond.
 ///
+/// # Pausing
+///
+/// Expirations are measured on the runtime's clock, through
+/// [`Instant::now`] and [`sleep_until`]. On a runtime with a pausable clock,
+/// no item expires while the clock is paused, and each item expires once the
+/// clock has run for the logical time it was inserted with.
+///
 /// # Implementation
 ///
 /// The [`DelayQueue`] is backed by a separate instance of a timer wheel similar to that used internally
@@ -122,6 +129,8 @@ use std::task::{self, Poll};
 /// [`Stream::poll_expired`]: method@Self::poll_expired
 /// [`DelayQueue`]: struct@DelayQueue
 /// [`sleep`]: fn@tokio::time::sleep
+/// [`sleep_until`]: fn@tokio::time::sleep_until
+/// [`Instant::now`]: tokio::time::Instant::now
 /// [`slab`]: slab
 /// [`capacity`]: method@Self::capacity
 /// [`reserve`]: method@Self::reserve
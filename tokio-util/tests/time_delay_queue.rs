@@ -563,6 +563,40 @@ async fn insert_in_past_after_poll_fires_immediately() {
     assert_eq!(entry, "bar");
 }
 
+#[tokio::test]
+async fn expirations_follow_logical_time_across_pause() {
+    time::pause();
+
+    let mut queue = task::spawn(DelayQueue::new());
+
+    let now = Instant::now();
+
+    queue.insert_at("foo", now + ms(100));
+    queue.insert_at("bar", now + ms(200));
+
+    assert_pending!(poll!(queue));
+
+    // Real time passing while the clock is frozen does not expire anything.
+    std::thread::sleep(ms(250));
+    assert!(!queue.is_woken());
+    assert_pending!(poll!(queue));
+
+    time::resume();
+    assert_pending!(poll!(queue));
+    time::pause();
+
+    sleep(ms(100)).await;
+
+    let entry = assert_ready_ok!(poll!(queue)).into_inner();
+    assert_eq!(entry, "foo");
+    assert_pending!(poll!(queue));
+
+    sleep(ms(100)).await;
+
+    let entry = assert_ready_ok!(poll!(queue)).into_inner();
+    assert_eq!(entry, "bar");
+}
+
 fn ms(n: u64) -> Duration {
     Duration::from_millis(n)
 }
@@ -25,6 +25,13 @@ pub(crate) mod sync {
         }
     }
     pub(crate) use loom::sync::*;
+
+    pub(crate) mod atomic {
+        pub(crate) use loom::sync::atomic::*;
+
+        // Loom's atomics cannot be initialized in a `static`.
+        pub(crate) use std::sync::atomic::AtomicU64 as StaticAtomicU64;
+    }
 }
 
 pub(crate) mod rand {
@@ -2,7 +2,7 @@
 //! re-export of `AtomicU64`. On 32 bit platforms, this is implemented using a
 //! `Mutex`.
 
-pub(crate) use self::imp::AtomicU64;
+pub(crate) use self::imp::{AtomicU64, StaticAtomicU64};
 
 // `AtomicU64` can only be used on targets with `target_has_atomic` is 64 or greater.
 // Once `cfg_target_has_atomic` feature is stable, we can replace it with
@@ -11,11 +11,13 @@ pub(crate) use self::imp::AtomicU64;
 #[cfg(not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")))]
 mod imp {
     pub(crate) use std::sync::atomic::AtomicU64;
+    pub(crate) use std::sync::atomic::AtomicU64 as StaticAtomicU64;
 }
 
 #[cfg(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))]
 mod imp {
-    use std::sync::atomic::Ordering;
+    use std::cell::UnsafeCell;
+    use std::sync::atomic::{spin_loop_hint, AtomicBool, Ordering};
     use std::sync::Mutex;
 
     #[derive(Debug)]
@@ -38,6 +40,13 @@ mod imp {
             *self.inner.lock().unwrap() = val;
         }
 
+        pub(crate) fn fetch_add(&self, val: u64, _: Ordering) -> u64 {
+            let mut lock = self.inner.lock().unwrap();
+            let prev = *lock;
+            *lock = prev.wrapping_add(val);
+            prev
+        }
+
         pub(crate) fn fetch_or(&self, val: u64, _: Ordering) -> u64 {
             let mut lock = self.inner.lock().unwrap();
             let prev = *lock;
@@ -57,4 +66,40 @@ mod imp {
             prev
         }
     }
+
+    /// `AtomicU64` that can be initialized in a `static`. `Mutex::new` is
+    /// not `const`, so the value is guarded by a spin lock instead, which is
+    /// only ever held for a single addition.
+    pub(crate) struct StaticAtomicU64 {
+        locked: AtomicBool,
+        value: UnsafeCell<u64>,
+    }
+
+    unsafe impl Sync for StaticAtomicU64 {}
+
+    impl StaticAtomicU64 {
+        pub(crate) const fn new(val: u64) -> StaticAtomicU64 {
+            StaticAtomicU64 {
+                locked: AtomicBool::new(false),
+                value: UnsafeCell::new(val),
+            }
+        }
+
+        pub(crate) fn fetch_add(&self, val: u64, _: Ordering) -> u64 {
+            while self.locked.compare_and_swap(false, true, Ordering::Acquire) {
+                spin_loop_hint();
+            }
+
+            // Safety: holding the lock gives exclusive access to the value.
+            let prev = unsafe {
+                let value = &mut *self.value.get();
+                let prev = *value;
+                *value = prev.wrapping_add(val);
+                prev
+            };
+
+            self.locked.store(false, Ordering::Release);
+            prev
+        }
+    }
 }
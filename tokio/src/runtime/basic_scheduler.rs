@@ -2,6 +2,7 @@ use crate::future::poll_fn;
 use crate::loom::sync::Mutex;
 use crate::park::{Park, Unpark};
 use crate::runtime::task::{self, JoinHandle, Schedule, Task};
+use crate::runtime::Quiesce;
 use crate::sync::notify::Notify;
 use crate::util::linked_list::{Link, LinkedList};
 use crate::util::{waker_ref, Wake, WakerRef};
@@ -46,6 +47,9 @@ struct Inner<P: Park> {
 
     /// Thread park handle
     park: P,
+
+    /// Tracks whether the scheduler is parked while the clock is paused
+    quiesce: Quiesce,
 }
 
 #[derive(Clone)]
@@ -94,7 +98,7 @@ const REMOTE_FIRST_INTERVAL: u8 = 31;
 scoped_thread_local!(static CURRENT: Context);
 
 impl<P: Park> BasicScheduler<P> {
-    pub(crate) fn new(park: P) -> BasicScheduler<P> {
+    pub(crate) fn new(park: P, quiesce: Quiesce) -> BasicScheduler<P> {
         let unpark = Box::new(park.unpark());
 
         let spawner = Spawner {
@@ -112,6 +116,7 @@ impl<P: Park> BasicScheduler<P> {
             spawner: spawner.clone(),
             tick: 0,
             park,
+            quiesce,
         }));
 
         BasicScheduler {
@@ -175,6 +180,7 @@ impl<P: Park> Inner<P> {
     fn block_on<F: Future>(&mut self, future: F) -> F::Output {
         enter(self, |scheduler, context| {
             let _enter = crate::runtime::enter(false);
+            let _active = scheduler.quiesce.enter();
             let waker = scheduler.spawner.waker_ref();
             let mut cx = std::task::Context::from_waker(&waker);
 
@@ -208,7 +214,9 @@ impl<P: Park> Inner<P> {
                         Some(task) => crate::coop::budget(|| task.run()),
                         None => {
                             // Park until the thread is signaled
+                            scheduler.quiesce.before_park();
                             scheduler.park.park().ok().expect("failed to park");
+                            scheduler.quiesce.after_unpark();
 
                             // Try polling the `block_on` future next
                             continue 'outer;
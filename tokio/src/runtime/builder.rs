@@ -1,6 +1,5 @@
 use crate::runtime::handle::Handle;
-use crate::runtime::{blocking, driver, Callback, PausableTimeConfig, Runtime, Spawner};
-use super::driver::{create_clock, create_pausable_clock};
+use crate::runtime::{blocking, driver, Callback, PausableTimeConfig, Quiesce, Runtime, Spawner};
 use std::fmt;
 use std::io;
 use std::time::Duration;
@@ -50,6 +49,34 @@ pub struct Builder {
     /// Whether or not time is pausable and how to start the system
     pausable_time_cfg: Option<PausableTimeConfig>,
 
+    /// To run each time the pausable clock is paused
+    on_time_pause: Option<Callback>,
+
+    /// To run each time the pausable clock is resumed
+    on_time_resume: Option<Callback>,
+
+    /// To run each time the pausable clock is advanced
+    on_time_warp: Option<driver::OnTimeWarp>,
+
+    /// Whether the clock's fallible operations panic or fail on misuse
+    clock_panic_policy: driver::PanicPolicy,
+
+    /// Which timers a pause of the clock freezes
+    pause_scope: driver::PauseScope,
+
+    /// To run once all workers are parked after the clock is paused
+    on_quiesced_pause: Option<Callback>,
+
+    /// Clock to use instead of creating one, possibly shared with other
+    /// runtimes
+    clock: Option<driver::Clock>,
+
+    /// Source the created clock reads its time from
+    time_source: Option<driver::TimeSource>,
+
+    /// To run each time the time driver fires a timer
+    on_timer_fire: Option<driver::OnTimerFire>,
+
     /// The number of worker threads, used by Runtime.
     ///
     /// Only used when not using the current-thread executor.
@@ -115,6 +142,28 @@ impl Builder {
 
             pausable_time_cfg: None,
 
+            // No clock transition callbacks
+            on_time_pause: None,
+            on_time_resume: None,
+            on_time_warp: None,
+
+            // Misusing the clock panics
+            clock_panic_policy: Default::default(),
+
+            // Pauses freeze every timer
+            pause_scope: Default::default(),
+
+            // Create a clock for the runtime
+            clock: None,
+
+            // Read the time from the system
+            time_source: None,
+
+            // No timer firing callback
+            on_timer_fire: None,
+
+            on_quiesced_pause: None,
+
             // Default to lazy auto-detection (one thread per CPU core)
             worker_threads: None,
 
@@ -386,6 +435,8 @@ impl Builder {
         driver::Cfg {
             enable_io: self.enable_io,
             enable_time: self.enable_time,
+            on_timer_fire: self.on_timer_fire.clone(),
+            pause_scope: self.pause_scope,
         }
     }
 
@@ -414,11 +465,8 @@ impl Builder {
     fn build_basic_runtime(&mut self) -> io::Result<Runtime> {
         use crate::runtime::{BasicScheduler, Kind};
 
-        let clock = if let Some(pausable_config) = &self.pausable_time_cfg {
-            create_pausable_clock(pausable_config.start_paused, pausable_config.elapsed_time)
-        } else {
-            create_clock()
-        };
+        let quiesce = Quiesce::new(self.on_quiesced_pause.clone());
+        let clock = self.create_clock(&quiesce)?;
 
         let (driver, resources) = driver::Driver::new(self.get_cfg(), clock.clone())?;
 
@@ -426,7 +474,7 @@ impl Builder {
         // there are no futures ready to do something, it'll let the timer or
         // the reactor to generate some new stimuli for the futures to continue
         // in their life.
-        let scheduler = BasicScheduler::new(driver);
+        let scheduler = BasicScheduler::new(driver, quiesce);
         let spawner = Spawner::Basic(scheduler.spawner().clone());
 
         // Blocking pool
@@ -493,18 +541,816 @@ cfg_time! {
             self
         }
 
+        /// Creates the configured runtime with a pausable clock.
+        ///
+        /// This is like [`build`], except that the runtime is given a pausable
+        /// clock if none was configured, and is returned as a
+        /// [`PausableRuntime`] whose handle can pause the clock without
+        /// checking for errors. A clock configured with [`pausable_time`],
+        /// [`start_paused`], [`elapsed_time`] or [`clock`] is used as is.
+        /// The builder's configuration is left unchanged.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .build_pausable()
+        ///     .unwrap();
+        ///
+        /// assert!(rt.handle().is_paused());
+        /// ```
+        ///
+        /// [`build`]: method@Self::build
+        /// [`pausable_time`]: method@Self::pausable_time
+        /// [`start_paused`]: method@Self::start_paused
+        /// [`elapsed_time`]: method@Self::elapsed_time
+        /// [`clock`]: method@Self::clock
+        /// [`PausableRuntime`]: crate::runtime::PausableRuntime
+        pub fn build_pausable(&mut self) -> io::Result<crate::runtime::PausableRuntime> {
+            // The default pausable clock only applies to this build, so that
+            // the builder can still build runtimes as configured.
+            let pausable_time_cfg = self.pausable_time_cfg;
+
+            if self.clock.is_none() {
+                self.pausable_time_cfg.get_or_insert_with(PausableTimeConfig::default);
+            }
+
+            let rt = self.build();
+            self.pausable_time_cfg = pausable_time_cfg;
+
+            rt.map(crate::runtime::PausableRuntime::new)
+        }
+
         /// Configure pausability of time
+        ///
+        /// The runtime's clock starts paused if `start_paused` is `true`, and
+        /// reports `elapsed_time` as already elapsed when the runtime starts.
+        ///
+        /// Pausing and resuming the clock take effect as soon as the call
+        /// returns: the clock does not poll for its state, so it has no
+        /// pause-check resolution to tune. Timers fire with the one
+        /// millisecond resolution of the time driver, whether the clock is
+        /// pausable or not.
+        ///
+        /// When the runtime shuts down, its clock is resumed, however deeply
+        /// it is paused, so that tasks blocked on a pause, such as blocking
+        /// tasks waiting for a resume, do not keep the shutdown from
+        /// completing. Timers still pending are cancelled.
         pub fn pausable_time(&mut self,
             start_paused: bool,
             elapsed_time: std::time::Duration
         ) -> &mut Self
         {
-            self.pausable_time_cfg = Some(PausableTimeConfig {
-                start_paused,
-                elapsed_time
-            });
+            let cfg = self.pausable_time_cfg.get_or_insert_with(PausableTimeConfig::default);
+            cfg.start_paused = start_paused;
+            cfg.elapsed_time = elapsed_time;
+            self
+        }
+
+        /// Configures the runtime to use a pausable clock that starts paused.
+        ///
+        /// When the runtime is built, its clock is already frozen and no
+        /// `Sleep` will complete until the clock is resumed with
+        /// [`Handle::resume_time`].
+        ///
+        /// This selects the pausable clock backend. The time driver must be
+        /// enabled, otherwise [`build`] returns an error.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// assert!(rt.handle().is_time_paused());
+        /// ```
+        ///
+        /// [`Handle::resume_time`]: crate::runtime::Handle::resume_time
+        /// [`build`]: method@Self::build
+        pub fn start_paused(&mut self, start_paused: bool) -> &mut Self {
+            self.pausable_time_cfg
+                .get_or_insert_with(PausableTimeConfig::default)
+                .start_paused = start_paused;
+            self
+        }
+
+        /// Configures the runtime to use a pausable clock that reports
+        /// `elapsed_time` as already elapsed when the runtime starts.
+        ///
+        /// This is useful to reproduce behavior that depends on a large
+        /// monotonic offset, or to align the elapsed time of several runtimes.
+        ///
+        /// This selects the pausable clock backend. The time driver must be
+        /// enabled, otherwise [`build`] returns an error for a non-zero
+        /// `elapsed_time`. The `test-util` clock does not support a starting
+        /// offset either, so when that feature is enabled [`build`] also
+        /// returns an error for a non-zero `elapsed_time`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .elapsed_time(Duration::from_secs(60))
+        ///     .build();
+        /// ```
+        ///
+        /// [`build`]: method@Self::build
+        pub fn elapsed_time(&mut self, elapsed_time: Duration) -> &mut Self {
+            self.pausable_time_cfg
+                .get_or_insert_with(PausableTimeConfig::default)
+                .elapsed_time = elapsed_time;
+            self
+        }
+
+        /// Configures the runtime to use a pausable clock whose logical time
+        /// runs at `scale` times the rate of real time.
+        ///
+        /// A scale of `2.0` makes a one second `sleep` complete after half a
+        /// second, which is useful for soak tests that want accelerated
+        /// timers without freezing time altogether. A scale of `0.0` stops
+        /// logical time like a pause, except that [`Handle::is_time_paused`]
+        /// still reports `false`. Pausing and advancing the clock work as
+        /// usual at any scale.
+        ///
+        /// This selects the pausable clock backend. [`build`] returns an error
+        /// if `scale` is negative, not finite or greater than one million,
+        /// and if the `test-util` feature is enabled and `scale` is not
+        /// `1.0`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .time_scale(10.0)
+        ///     .build();
+        /// ```
+        ///
+        /// [`build`]: method@Self::build
+        /// [`Handle::is_time_paused`]: crate::runtime::Handle::is_time_paused
+        pub fn time_scale(&mut self, scale: f64) -> &mut Self {
+            self.pausable_time_cfg
+                .get_or_insert_with(PausableTimeConfig::default)
+                .time_scale = Some(scale);
+            self
+        }
+
+        /// Configures the runtime to use a pausable clock restored from
+        /// `state`, as captured by [`time::clock_state`].
+        ///
+        /// The restored clock reports the same logical time as the original
+        /// did when `state` was captured, and starts paused if the original
+        /// was paused. Real time that passed in between is not counted. See
+        /// [`ClockState`] for details.
+        ///
+        /// This overrides any previous call to [`start_paused`] or
+        /// [`elapsed_time`]. Like the latter, [`build`] returns an error for a
+        /// non-zero elapsed time when the `test-util` feature is enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::ClockState;
+        /// use std::time::Duration;
+        ///
+        /// let state = ClockState::new(Duration::from_secs(60), true, Duration::from_secs(5));
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .restore_clock(state)
+        ///     .build();
+        /// ```
+        ///
+        /// [`time::clock_state`]: crate::time::clock_state
+        /// [`ClockState`]: crate::time::ClockState
+        /// [`start_paused`]: method@Self::start_paused
+        /// [`elapsed_time`]: method@Self::elapsed_time
+        /// [`build`]: method@Self::build
+        pub fn restore_clock(&mut self, state: crate::time::ClockState) -> &mut Self {
+            let cfg = self.pausable_time_cfg.get_or_insert_with(PausableTimeConfig::default);
+            cfg.start_paused = state.is_paused();
+            cfg.elapsed_time = state.elapsed();
+            cfg.paused_duration = state.paused_duration();
+            self
+        }
+
+        /// Returns the configuration of the pausable clock the runtime will be
+        /// built with, or `None` if it will not have one.
+        ///
+        /// The configuration is the one set with [`pausable_time`],
+        /// [`start_paused`], [`elapsed_time`], [`time_scale`] and
+        /// [`restore_clock`]. A clock shared through [`clock`] is configured
+        /// when it is created, so it is not reflected here.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let mut builder = runtime::Builder::new_current_thread();
+        /// assert!(builder.time_config().is_none());
+        ///
+        /// builder.start_paused(true).elapsed_time(Duration::from_secs(60));
+        ///
+        /// let cfg = builder.time_config().unwrap();
+        /// assert!(cfg.start_paused());
+        /// assert_eq!(Duration::from_secs(60), cfg.elapsed_time());
+        /// ```
+        ///
+        /// [`pausable_time`]: method@Self::pausable_time
+        /// [`start_paused`]: method@Self::start_paused
+        /// [`elapsed_time`]: method@Self::elapsed_time
+        /// [`time_scale`]: method@Self::time_scale
+        /// [`restore_clock`]: method@Self::restore_clock
+        /// [`clock`]: method@Self::clock
+        pub fn time_config(&self) -> Option<PausableTimeConfig> {
+            self.pausable_time_cfg
+        }
+
+        /// Configures the runtime to use `clock` as its clock.
+        ///
+        /// Runtimes built with clones of the same [`LocalClock`] share one
+        /// timeline: pausing, resuming or advancing the clock through any of
+        /// them, or through the `LocalClock` itself, applies to all of them.
+        /// Their timers freeze together while the clock is paused, and an
+        /// advance fires the timers it reaches in every runtime, which suits
+        /// simulating several nodes on one logical time.
+        ///
+        /// Unlike a clock the runtime creates for itself, a shared clock is
+        /// left paused when the runtimes using it shut down.
+        ///
+        /// The clock is configured when it is created, so [`build`] returns
+        /// an error if this is combined with [`pausable_time`],
+        /// [`start_paused`], [`elapsed_time`], [`time_scale`],
+        /// [`restore_clock`], [`time_source`], [`on_time_pause`],
+        /// [`on_time_resume`], [`on_time_warp`] or [`clock_panic_policy`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::LocalClock;
+        ///
+        /// let clock = LocalClock::new(false);
+        ///
+        /// let first = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .clock(clock.clone())
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// let second = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .clock(clock.clone())
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// clock.pause();
+        /// assert!(first.handle().is_time_paused());
+        /// assert!(second.handle().is_time_paused());
+        /// ```
+        ///
+        /// [`LocalClock`]: crate::time::LocalClock
+        /// [`build`]: method@Self::build
+        /// [`pausable_time`]: method@Self::pausable_time
+        /// [`start_paused`]: method@Self::start_paused
+        /// [`elapsed_time`]: method@Self::elapsed_time
+        /// [`time_scale`]: method@Self::time_scale
+        /// [`restore_clock`]: method@Self::restore_clock
+        /// [`time_source`]: method@Self::time_source
+        /// [`on_time_pause`]: method@Self::on_time_pause
+        /// [`on_time_resume`]: method@Self::on_time_resume
+        /// [`on_time_warp`]: method@Self::on_time_warp
+        /// [`clock_panic_policy`]: method@Self::clock_panic_policy
+        pub fn clock(&mut self, clock: crate::time::LocalClock) -> &mut Self {
+            self.clock = Some(clock.as_clock().clone());
+            self
+        }
+
+        /// Configures the runtime to use a pausable clock that reads its time
+        /// from `source`.
+        ///
+        /// The clock is paused, resumed and advanced as usual, and tells the
+        /// source each time it pauses or resumes. See [`TimeSource`] for what
+        /// the source is expected to do. Without a source, the clock reads
+        /// its time from a [`PausableTimeSource`].
+        ///
+        /// This selects the pausable clock backend. [`build`] returns an error
+        /// if this is combined with [`elapsed_time`], [`time_scale`] or
+        /// [`restore_clock`], which the source is expected to account for
+        /// itself, and if the `test-util` feature is enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::PausableTimeSource;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .time_source(Box::new(PausableTimeSource::new()))
+        ///     .build();
+        ///
+        /// // With the `test-util` feature, `build` rejects the time source.
+        /// if let Ok(rt) = rt {
+        ///     assert!(rt.handle().pause_time().unwrap());
+        /// }
+        /// ```
+        ///
+        /// [`TimeSource`]: crate::time::TimeSource
+        /// [`PausableTimeSource`]: crate::time::PausableTimeSource
+        /// [`build`]: method@Self::build
+        /// [`elapsed_time`]: method@Self::elapsed_time
+        /// [`time_scale`]: method@Self::time_scale
+        /// [`restore_clock`]: method@Self::restore_clock
+        pub fn time_source(&mut self, source: Box<dyn crate::time::TimeSource>) -> &mut Self {
+            self.pausable_time_cfg.get_or_insert_with(PausableTimeConfig::default);
+            self.time_source = Some(source.into());
+            self
+        }
+
+        /// Executes function `f` each time the runtime's pausable clock is
+        /// paused.
+        ///
+        /// The callback runs synchronously on the thread that paused the
+        /// clock, after the clock is frozen and before any task waiting for
+        /// the pause is woken. The clock cannot be resumed while the callback
+        /// runs, so it observes a stable frozen instant.
+        ///
+        /// A resume waits for the callback to return, so `f` must not resume
+        /// the clock, nor wait for anything that does: doing so deadlocks.
+        ///
+        /// Only runtimes with a pausable clock ever invoke this callback.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use tokio::runtime;
+        /// # use std::time::Duration;
+        ///
+        /// # pub fn main() {
+        /// let runtime = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .on_time_pause(|| {
+        ///         println!("time paused");
+        ///     })
+        ///     .build();
+        /// # }
+        /// ```
+        pub fn on_time_pause<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.on_time_pause = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Executes function `f` each time the runtime's pausable clock is
+        /// resumed.
+        ///
+        /// The callback runs synchronously on the thread that resumed the
+        /// clock, after the clock is running again and before any task waiting
+        /// for the resume is woken. The clock cannot be paused while the
+        /// callback runs.
+        ///
+        /// A pause waits for the callback to return, so `f` must not pause
+        /// the clock, nor wait for anything that does: doing so deadlocks.
+        ///
+        /// Only runtimes with a pausable clock ever invoke this callback.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use tokio::runtime;
+        /// # use std::time::Duration;
+        ///
+        /// # pub fn main() {
+        /// let runtime = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .on_time_resume(|| {
+        ///         println!("time resumed");
+        ///     })
+        ///     .build();
+        /// # }
+        /// ```
+        pub fn on_time_resume<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.on_time_resume = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Executes function `f` each time the runtime's pausable clock is
+        /// advanced.
+        ///
+        /// `f` is passed the logical instant of the clock before and after the
+        /// advance, whether it was made by [`Handle::advance_time`],
+        /// [`Handle::advance_to`], [`Handle::resume_with_advance`] or by the
+        /// time driver skipping ahead to the next timer. An advance that leaves the clock where it was does
+        /// not invoke `f`. Restoring a clock with [`restore_clock`] does not
+        /// invoke it either, as it only sets where the new clock starts.
+        ///
+        /// The callback runs synchronously on the thread that advanced the
+        /// clock, once the clock has moved and without holding any of its
+        /// locks. Pausing, resuming or advancing the clock from within `f`
+        /// panics, or fails with an error under [`clock_panic_policy`]. The
+        /// clocks of other runtimes may be used freely from within `f`.
+        ///
+        /// Only runtimes with a pausable clock ever invoke this callback. It
+        /// cannot be combined with a clock shared through [`clock`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use tokio::runtime;
+        ///
+        /// # pub fn main() {
+        /// let runtime = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .on_time_warp(|old, new| {
+        ///         println!("time advanced by {:?}", new - old);
+        ///     })
+        ///     .build();
+        /// # }
+        /// ```
+        ///
+        /// [`Handle::advance_time`]: crate::runtime::Handle::advance_time
+        /// [`Handle::advance_to`]: crate::runtime::Handle::advance_to
+        /// [`Handle::resume_with_advance`]: crate::runtime::Handle::resume_with_advance
+        /// [`restore_clock`]: method@Self::restore_clock
+        /// [`clock`]: method@Self::clock
+        /// [`clock_panic_policy`]: method@Self::clock_panic_policy
+        pub fn on_time_warp<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(crate::time::Instant, crate::time::Instant) + Send + Sync + 'static,
+        {
+            self.on_time_warp = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets how the fallible operations of the runtime's clock report
+        /// misuse.
+        ///
+        /// With the default, [`PanicPolicy::Panic`], misusing the clock
+        /// panics, even through methods that return a [`ClockError`] such as
+        /// [`Handle::pause_time`] or [`time::try_advance`]. With
+        /// [`PanicPolicy::Error`], those methods return an error instead,
+        /// for instance [`ClockError::InWarpCallback`] when called from
+        /// within an [`on_time_warp`] callback. Methods that cannot fail keep
+        /// panicking.
+        ///
+        /// The policy is part of the clock, so it cannot be combined with a
+        /// clock shared through [`clock`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::error::PanicPolicy;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .clock_panic_policy(PanicPolicy::Error)
+        ///     .build()
+        ///     .unwrap();
+        /// # drop(rt);
+        /// ```
+        ///
+        /// [`PanicPolicy::Panic`]: crate::time::error::PanicPolicy::Panic
+        /// [`PanicPolicy::Error`]: crate::time::error::PanicPolicy::Error
+        /// [`ClockError`]: crate::time::error::ClockError
+        /// [`ClockError::InWarpCallback`]: crate::time::error::ClockError::InWarpCallback
+        /// [`Handle::pause_time`]: crate::runtime::Handle::pause_time
+        /// [`time::try_advance`]: crate::time::try_advance
+        /// [`on_time_warp`]: method@Self::on_time_warp
+        /// [`clock`]: method@Self::clock
+        pub fn clock_panic_policy(&mut self, policy: crate::time::error::PanicPolicy) -> &mut Self {
+            self.clock_panic_policy = policy;
+            self
+        }
+
+        /// Sets which timers are frozen while the runtime's clock is paused.
+        ///
+        /// With the default, [`PauseScope::AllTimers`], pausing the clock
+        /// freezes every timer. With [`PauseScope::FutureTimers`], it only
+        /// freezes the timers registered while the clock is paused: the
+        /// timers that the time driver has registered when the clock gets
+        /// paused keep counting down in real time until they fire. This
+        /// helps migrate a system to pause-aware timers one part at a time.
+        ///
+        /// A timer is registered by the time driver the next time the
+        /// runtime parks after its `Sleep` is created. Tasks of a
+        /// multi-thread runtime do not run while its clock is paused, so a
+        /// timer firing in the middle of a pause only wakes its task once
+        /// the clock resumes there.
+        ///
+        /// The scope belongs to the time driver rather than to the clock, so
+        /// it can be combined with a clock shared through [`clock`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration, PauseScope};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .pause_scope(PauseScope::FutureTimers)
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     let armed = time::sleep(Duration::from_millis(10));
+        ///     tokio::task::yield_now().await;
+        ///
+        ///     time::try_pause().unwrap();
+        ///
+        ///     // Fires after 10ms of real time, although the clock is paused.
+        ///     armed.await;
+        ///     assert!(time::is_paused());
+        ///
+        ///     time::try_resume().unwrap();
+        /// });
+        /// ```
+        ///
+        /// [`PauseScope::AllTimers`]: crate::time::PauseScope::AllTimers
+        /// [`PauseScope::FutureTimers`]: crate::time::PauseScope::FutureTimers
+        /// [`clock`]: method@Self::clock
+        pub fn pause_scope(&mut self, scope: crate::time::PauseScope) -> &mut Self {
+            self.pause_scope = scope;
             self
         }
+
+        /// Executes function `f` once the runtime's pausable clock is paused
+        /// and no worker thread is polling a task.
+        ///
+        /// Unlike [`on_time_pause`], which runs as soon as the clock is
+        /// paused, this waits for the runtime to quiesce: the clock must be
+        /// paused, and every worker thread must have parked for lack of work.
+        /// This gives a moment at which no task of the runtime makes progress,
+        /// for example to snapshot state that tasks would otherwise mutate.
+        ///
+        /// Quiescence is detected by the schedulers themselves. Each worker
+        /// reports when it starts driving the runtime, when it parks and when
+        /// it is unparked. `f` then runs on whichever thread completes the
+        /// condition: the thread that paused the clock if all workers were
+        /// already parked, or the last worker to park, right before it parks.
+        /// `f` runs at most once per pause.
+        ///
+        /// There is no timeout. A task that never yields keeps its worker
+        /// busy, and `f` is not run until it yields and its worker runs out of
+        /// work. If the clock is resumed before that happens, `f` is not run
+        /// for that pause at all.
+        ///
+        /// While `f` runs, workers woken for instance by I/O events wait for
+        /// it to return before polling any task, and timers do not fire. The
+        /// clock may still be resumed from another thread, so `f` should not
+        /// assume that it stays paused. Only workers are accounted for: the
+        /// future passed to [`Runtime::block_on`] on a multi-thread runtime,
+        /// and closures passed to [`spawn_blocking`], may still be running.
+        /// `f` must not pause or resume the clock, nor wait on the runtime's
+        /// tasks.
+        ///
+        /// On a multi-thread runtime, a worker woken while the clock is paused
+        /// waits for the clock to resume before polling its task, and counts
+        /// as busy in the meantime.
+        ///
+        /// Only runtimes with a pausable clock ever invoke this callback. It
+        /// cannot be combined with a clock shared through [`clock`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use tokio::runtime;
+        /// # use std::time::Duration;
+        ///
+        /// # pub fn main() {
+        /// let runtime = runtime::Builder::new_multi_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .on_quiesced_pause(|| {
+        ///         println!("time paused and all workers idle");
+        ///     })
+        ///     .build();
+        /// # }
+        /// ```
+        ///
+        /// [`on_time_pause`]: method@Self::on_time_pause
+        /// [`clock`]: method@Self::clock
+        /// [`Runtime::block_on`]: crate::runtime::Runtime::block_on
+        /// [`spawn_blocking`]: crate::task::spawn_blocking
+        pub fn on_quiesced_pause<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.on_quiesced_pause = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Executes function `f` each time the time driver fires a timer.
+        ///
+        /// `f` is passed the deadline of the timer and the number of timers
+        /// still waiting to fire. Combined with a paused clock advanced step
+        /// by step, this traces exactly which timers fire on each advance,
+        /// which helps to debug nondeterministic tests. The deadline of a
+        /// timer measured in real time, such as a [`sleep_realtime`], is the
+        /// real instant at which it fires.
+        ///
+        /// The callback runs synchronously on the thread driving the timers,
+        /// right after the timer's task is woken, so it should return
+        /// quickly. The driver does not hold any lock while it runs, and
+        /// runtimes built without this callback do no extra work.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use tokio::runtime;
+        ///
+        /// # pub fn main() {
+        /// let runtime = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .on_timer_fire(|deadline, remaining| {
+        ///         println!("timer at {:?} fired, {} left", deadline, remaining);
+        ///     })
+        ///     .build();
+        /// # }
+        /// ```
+        ///
+        /// [`sleep_realtime`]: crate::time::sleep_realtime
+        pub fn on_timer_fire<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(crate::time::Instant, usize) + Send + Sync + 'static,
+        {
+            self.on_timer_fire = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Returns the callbacks to run on each pause and resume of the clock,
+        /// which also keep `quiesce` informed of the clock's state.
+        fn time_callbacks(&self, quiesce: &Quiesce) -> (Option<Callback>, Option<Callback>) {
+            if !quiesce.is_enabled() {
+                return (self.on_time_pause.clone(), self.on_time_resume.clone());
+            }
+
+            let on_time_pause = self.on_time_pause.clone();
+            let paused = quiesce.clone();
+            let on_pause: Callback = std::sync::Arc::new(move || {
+                if let Some(f) = &on_time_pause {
+                    f();
+                }
+
+                paused.paused();
+            });
+
+            let on_time_resume = self.on_time_resume.clone();
+            let resumed = quiesce.clone();
+            let on_resume: Callback = std::sync::Arc::new(move || {
+                resumed.resumed();
+
+                if let Some(f) = &on_time_resume {
+                    f();
+                }
+            });
+
+            (Some(on_pause), Some(on_resume))
+        }
+
+        fn create_clock(&self, quiesce: &Quiesce) -> io::Result<driver::Clock> {
+            if let Some(clock) = &self.clock {
+                if self.pausable_time_cfg.is_some()
+                    || self.on_time_pause.is_some()
+                    || self.on_time_resume.is_some()
+                    || self.on_time_warp.is_some()
+                    || self.clock_panic_policy != Default::default()
+                    || quiesce.is_enabled()
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "a shared clock cannot be configured by the runtime builder",
+                    ));
+                }
+
+                return Ok(clock.clone());
+            }
+
+            match &self.pausable_time_cfg {
+                Some(cfg) => {
+                    if cfg.start_paused && !self.enable_time {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "starting paused requires the time driver to be enabled",
+                        ));
+                    }
+
+                    if cfg.elapsed_time != Duration::from_secs(0) && !self.enable_time {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "an elapsed time requires the time driver to be enabled",
+                        ));
+                    }
+
+                    if driver::Clock::is_test() && cfg.elapsed_time != Duration::from_secs(0) {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "elapsed time is not supported by the test-util clock",
+                        ));
+                    }
+
+                    if self.time_source.is_some() {
+                        if cfg.elapsed_time != Duration::from_secs(0)
+                            || cfg.paused_duration != Duration::from_secs(0)
+                            || cfg.time_scale.is_some()
+                        {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "a time source cannot be combined with an elapsed time, \
+                                 a time scale or a restored clock",
+                            ));
+                        }
+
+                        if driver::Clock::is_test() {
+                            return Err(io::Error::new(
+                                io::ErrorKind::Other,
+                                "time sources are not supported by the test-util clock",
+                            ));
+                        }
+                    }
+
+                    let (on_pause, on_resume) = self.time_callbacks(quiesce);
+                    let clock = driver::create_pausable_clock(cfg.start_paused, cfg.elapsed_time)
+                        .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "elapsed time is too large for the platform's clock",
+                            )
+                        })?;
+
+                    let mut clock = clock
+                        .with_callbacks(on_pause, on_resume)
+                        .with_warp_callback(self.on_time_warp.clone())
+                        .with_panic_policy(self.clock_panic_policy)
+                        .with_resume_on_shutdown()
+                        .with_paused_duration(cfg.paused_duration);
+
+                    if let Some(source) = &self.time_source {
+                        clock = clock.with_time_source(source.clone());
+                    }
+
+                    if let Some(scale) = cfg.time_scale {
+                        if !(0.0..=crate::time::MAX_TIME_SCALE).contains(&scale) {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "time scale must be between zero and one million",
+                            ));
+                        }
+
+                        if driver::Clock::is_test() && (scale - 1.0).abs() > f64::EPSILON {
+                            return Err(io::Error::new(
+                                io::ErrorKind::Other,
+                                "time scale is not supported by the test-util clock",
+                            ));
+                        }
+
+                        clock = clock.with_time_scale(scale);
+                    }
+
+                    Ok(clock)
+                }
+                None => Ok(driver::create_clock()),
+            }
+        }
+    }
+}
+
+cfg_not_time! {
+    impl Builder {
+        fn create_clock(&self, _quiesce: &Quiesce) -> io::Result<driver::Clock> {
+            Ok(driver::create_clock())
+        }
     }
 }
 
@@ -519,14 +1365,12 @@ cfg_rt_multi_thread! {
             let core_threads = self.worker_threads.unwrap_or_else(|| cmp::min(self.max_threads, num_cpus()));
             assert!(core_threads <= self.max_threads, "Core threads number cannot be above max limit");
 
-            let clock = if let Some(pausable_config) = &self.pausable_time_cfg {
-                create_pausable_clock(pausable_config.start_paused, pausable_config.elapsed_time)
-            } else {
-                create_clock()
-            };
+            let quiesce = Quiesce::new(self.on_quiesced_pause.clone());
+            let clock = self.create_clock(&quiesce)?;
             let (driver, resources) = driver::Driver::new(self.get_cfg(), clock.clone())?;
 
-            let (scheduler, launch) = ThreadPool::new(core_threads, Parker::new(driver), clock);
+            let (scheduler, launch) =
+                ThreadPool::new(core_threads, Parker::new(driver), clock, quiesce);
             let spawner = Spawner::ThreadPool(scheduler.spawner().clone());
 
             // Create the blocking pool
@@ -568,6 +1412,18 @@ impl fmt::Debug for Builder {
             .field("thread_stack_size", &self.thread_stack_size)
             .field("after_start", &self.after_start.as_ref().map(|_| "..."))
             .field("before_stop", &self.after_start.as_ref().map(|_| "..."))
+            .field("on_time_pause", &self.on_time_pause.as_ref().map(|_| "..."))
+            .field("on_time_resume", &self.on_time_resume.as_ref().map(|_| "..."))
+            .field("on_time_warp", &self.on_time_warp.as_ref().map(|_| "..."))
+            .field("clock_panic_policy", &self.clock_panic_policy)
+            .field("pause_scope", &self.pause_scope)
+            .field("clock", &self.clock)
+            .field("time_source", &self.time_source)
+            .field("on_timer_fire", &self.on_timer_fire.as_ref().map(|_| "..."))
+            .field(
+                "on_quiesced_pause",
+                &self.on_quiesced_pause.as_ref().map(|_| "..."),
+            )
             .finish()
     }
 }
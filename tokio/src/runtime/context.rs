@@ -38,14 +38,36 @@ cfg_time! {
         })
     }
 
-    cfg_test_util! {
-        pub(crate) fn clock() -> Option<crate::runtime::driver::Clock> {
-            CONTEXT.with(|ctx| match *ctx.borrow() {
-                Some(ref ctx) => Some(ctx.clock.clone()),
-                None => None,
-            })
-        }
+    use crate::runtime::driver::Clock;
+
+    thread_local! {
+        /// Clock of the entered runtime.
+        ///
+        /// Reading the time is far more frequent than entering a runtime, so
+        /// the clock is cached apart from `CONTEXT` and lent out by reference
+        /// instead of being cloned out of the `Handle` on every call. It is
+        /// kept in sync with `CONTEXT` by `enter` and `EnterGuard`.
+        static CLOCK: RefCell<Option<Clock>> = RefCell::new(None)
+    }
+
+    pub(crate) fn clock() -> Option<Clock> {
+        with_clock(|clock| clock.cloned())
     }
+
+    pub(crate) fn with_clock<F, R>(f: F) -> R
+    where
+        F: FnOnce(Option<&Clock>) -> R,
+    {
+        CLOCK.with(|clock| f(clock.borrow().as_ref()))
+    }
+
+    fn set_clock(handle: Option<&Handle>) {
+        CLOCK.with(|clock| *clock.borrow_mut() = handle.map(|handle| handle.clock.clone()));
+    }
+}
+
+cfg_not_time! {
+    fn set_clock(_: Option<&Handle>) {}
 }
 
 cfg_rt! {
@@ -61,6 +83,8 @@ cfg_rt! {
 ///
 /// [`Handle`]: Handle
 pub(crate) fn enter(new: Handle) -> EnterGuard {
+    set_clock(Some(&new));
+
     CONTEXT.with(|ctx| {
         let old = ctx.borrow_mut().replace(new);
         EnterGuard(old)
@@ -72,6 +96,8 @@ pub(crate) struct EnterGuard(Option<Handle>);
 
 impl Drop for EnterGuard {
     fn drop(&mut self) {
+        set_clock(self.0.as_ref());
+
         CONTEXT.with(|ctx| {
             *ctx.borrow_mut() = self.0.take();
         });
@@ -102,25 +102,33 @@ cfg_time! {
 
     pub(crate) type Clock = crate::time::Clock;
     pub(crate) type TimeHandle = Option<crate::time::driver::Handle>;
+    pub(crate) type OnTimerFire = crate::time::driver::OnFire;
+    pub(crate) type OnTimeWarp = crate::time::WarpCallback;
+    pub(crate) type PanicPolicy = crate::time::error::PanicPolicy;
+    pub(crate) type PauseScope = crate::time::PauseScope;
+    pub(crate) type TimeSource = std::sync::Arc<dyn crate::time::TimeSource>;
 
     pub(crate) fn create_clock() -> Clock {
         crate::time::Clock::new()
     }
 
-    #[allow(dead_code)]
-    pub(crate) fn create_pausable_clock(paused: bool, elapsed_time: Duration) -> Clock {
-        Clock::new_pausable(paused, elapsed_time)
+    pub(crate) fn create_pausable_clock(paused: bool, elapsed_time: Duration) -> Option<Clock> {
+        Clock::try_new_pausable(paused, elapsed_time)
     }
 
     fn create_time_driver(
         enable: bool,
         io_stack: IoStack,
         clock: Clock,
+        on_timer_fire: Option<OnTimerFire>,
+        pause_scope: PauseScope,
     ) -> (TimeDriver, TimeHandle) {
         use crate::park::either::Either;
 
         if enable {
-            let driver = crate::time::driver::Driver::new(io_stack, clock);
+            let driver = crate::time::driver::Driver::new(io_stack, clock)
+                .with_on_fire(on_timer_fire)
+                .with_pause_scope(pause_scope);
             let handle = driver.handle();
 
             (Either::A(driver), Some(handle))
@@ -135,8 +143,13 @@ cfg_not_time! {
 
     pub(crate) type Clock = ();
     pub(crate) type TimeHandle = ();
+    pub(crate) type OnTimerFire = ();
+    pub(crate) type OnTimeWarp = ();
+    pub(crate) type PanicPolicy = ();
+    pub(crate) type PauseScope = ();
+    pub(crate) type TimeSource = ();
 
-    fn create_clock() -> Clock {
+    pub(crate) fn create_clock() -> Clock {
         ()
     }
 
@@ -144,6 +157,8 @@ cfg_not_time! {
         _enable: bool,
         io_stack: IoStack,
         _clock: Clock,
+        _on_timer_fire: Option<OnTimerFire>,
+        _pause_scope: PauseScope,
     ) -> (TimeDriver, TimeHandle) {
         (io_stack, ())
     }
@@ -166,13 +181,20 @@ pub(crate) struct Resources {
 pub(crate) struct Cfg {
     pub(crate) enable_io: bool,
     pub(crate) enable_time: bool,
+    pub(crate) on_timer_fire: Option<OnTimerFire>,
+    pub(crate) pause_scope: PauseScope,
 }
 
 impl Driver {
     pub(crate) fn new(cfg: Cfg, clock: Clock) -> io::Result<(Self, Resources)> {
         let (io_stack, io_handle, signal_handle) = create_io_stack(cfg.enable_io)?;
-        let (time_driver, time_handle) =
-            create_time_driver(cfg.enable_time, io_stack, clock.clone());
+        let (time_driver, time_handle) = create_time_driver(
+            cfg.enable_time,
+            io_stack,
+            clock.clone(),
+            cfg.on_timer_fire,
+            cfg.pause_scope,
+        );
 
         Ok((
             Self { inner: time_driver },
@@ -204,11 +204,787 @@ impl Handle {
 }
 
 cfg_time! {
+    use crate::time::error::ClockError;
+    use crate::time::{PauseGuard, PauseTimer};
+
     impl Handle {
         /// Get the current instant according to the runtime's clock
         pub(crate) fn now(&self) -> crate::time::Instant {
             self.clock.now()
         }
+
+        /// Returns `true` if the runtime's clock is currently paused.
+        ///
+        /// Runtimes that were not built with a pausable clock are never
+        /// paused, so this returns `false` for them rather than panicking.
+        pub fn is_time_paused(&self) -> bool {
+            self.clock.is_paused()
+        }
+
+        /// Returns the source of time used by the runtime's clock.
+        ///
+        /// This does not take any lock, so it is cheap enough to call from
+        /// diagnostics that inspect many runtimes.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{ClockKind, Duration};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// assert_ne!(rt.handle().clock_kind(), ClockKind::System);
+        /// ```
+        pub fn clock_kind(&self) -> crate::time::ClockKind {
+            self.clock.kind()
+        }
+
+        /// Returns a [`PausableHandle`] to the runtime if its clock is
+        /// pausable, and `None` otherwise.
+        ///
+        /// This checks for a pausable clock once, so that the methods of the
+        /// returned handle need not.
+        pub fn pausable(&self) -> Option<crate::runtime::PausableHandle> {
+            crate::runtime::PausableHandle::new(self)
+        }
+
+        /// Pause the runtime's clock.
+        ///
+        /// While paused, the runtime's notion of "now" does not advance and no
+        /// scheduled `Sleep` will complete until the clock is resumed.
+        ///
+        /// Pauses nest: the clock is only resumed once every call to
+        /// `pause_time` has been matched by a call to [`resume_time`]. This
+        /// lets independent components pause time without resuming it from
+        /// under each other.
+        ///
+        /// Returns `Ok(true)` if this call paused the clock and `Ok(false)` if
+        /// the clock was already paused.
+        ///
+        /// Unlike the free functions of [`tokio::time`], this does not need to
+        /// be called from within the runtime: any thread holding a clone of
+        /// the `Handle` may pause the clock, and tasks on the runtime observe
+        /// the freeze as soon as this returns.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// let handle = rt.handle().clone();
+        /// std::thread::spawn(move || handle.pause_time().unwrap())
+        ///     .join()
+        ///     .unwrap();
+        ///
+        /// assert!(rt.block_on(async { tokio::time::is_paused() }));
+        /// ```
+        ///
+        /// [`resume_time`]: method@Self::resume_time
+        /// [`tokio::time`]: crate::time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        pub fn pause_time(&self) -> Result<bool, ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            self.clock.check_not_warping()?;
+            Ok(self.clock.pause())
+        }
+
+        /// Pause the runtime's clock and capture its frozen state in one
+        /// operation.
+        ///
+        /// The snapshot is taken as soon as the clock is frozen, before it can
+        /// be resumed and before any pause callback or task waiting for the
+        /// pause runs. It therefore reports exactly the instant and elapsed
+        /// time the clock froze at, which calling [`pause_time`] and then
+        /// reading the time cannot guarantee. This is useful to start a
+        /// deterministic section and record when it started.
+        ///
+        /// Like [`pause_time`], the pause nests and must be balanced by a call
+        /// to [`resume_time`]. If the clock was already paused, the snapshot
+        /// reports its current frozen state.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// let snapshot = rt.handle().pause_and_snapshot().unwrap();
+        /// assert!(snapshot.is_paused());
+        ///
+        /// rt.block_on(async {
+        ///     assert_eq!(snapshot.now(), tokio::time::Instant::now());
+        /// });
+        /// ```
+        ///
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`resume_time`]: method@Self::resume_time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        pub fn pause_and_snapshot(&self) -> Result<crate::time::ClockSnapshot, ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            self.clock.check_not_warping()?;
+            Ok(self.clock.pause_and_snapshot().1)
+        }
+
+        /// Advance the runtime's paused clock by `duration`.
+        ///
+        /// The frozen instant returned by `now()` moves forward by `duration`
+        /// and any `Sleep` whose deadline has been reached is completed. The
+        /// clock stays paused.
+        ///
+        /// The time driver is woken to fire those timers right away, so the
+        /// tasks waiting on them do not depend on other activity of the
+        /// runtime to be notified. On a multi-thread runtime, they are then
+        /// polled as soon as the clock resumes.
+        ///
+        /// Timers fire in the order of their deadlines, rounded up to the
+        /// millisecond. Timers sharing a deadline fire in the order they
+        /// were registered, which is the order their `Sleep`s were created
+        /// or last reset in, so that the tasks they wake are scheduled in a
+        /// reproducible order.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the runtime was not built with a pausable clock, if the
+        /// clock is not paused, or if advancing it by `duration` would
+        /// overflow it.
+        pub fn advance_time(&self, duration: std::time::Duration) {
+            self.clock.advance(duration);
+        }
+
+        /// Advance the runtime's paused clock to the absolute instant
+        /// `target`.
+        ///
+        /// This is [`advance_time`] for callers that know the instant they
+        /// want to reach rather than how far it is: the frozen instant
+        /// returned by `now()` becomes `target`, and any `Sleep` whose
+        /// deadline is at or before `target` is completed. The clock stays
+        /// paused. Advancing to the current instant does nothing.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built
+        /// with a pausable clock, [`ClockError::NotPaused`] if the clock is
+        /// not paused, [`ClockError::InPast`] if `target` is earlier than
+        /// the clock's current instant and [`ClockError::Overflow`] if the
+        /// clock cannot be advanced that far. The clock is left unchanged in
+        /// all cases.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{Duration, Instant};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// let start = rt.block_on(async { Instant::now() });
+        /// rt.handle().advance_to(start + Duration::from_secs(60)).unwrap();
+        ///
+        /// rt.block_on(async move {
+        ///     assert_eq!(start + Duration::from_secs(60), Instant::now());
+        /// });
+        /// ```
+        ///
+        /// [`advance_time`]: method@Self::advance_time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+        /// [`ClockError::InPast`]: crate::time::error::ClockError::InPast
+        /// [`ClockError::Overflow`]: crate::time::error::ClockError::Overflow
+        pub fn advance_to(&self, target: crate::time::Instant) -> Result<(), ClockError> {
+            self.clock.try_advance_to(target)
+        }
+
+        /// Returns the deadline of the runtime's earliest pending timer, or
+        /// `None` if no timer is pending.
+        ///
+        /// The deadline is an instant of the runtime's clock, rounded up to
+        /// the millisecond resolution of the time driver: advancing a paused
+        /// clock to it with [`advance_to`] fires that timer. Together, they
+        /// jump a paused clock from one timer to the next.
+        ///
+        /// A timer is pending from the moment its `Sleep` is created until it
+        /// fires or is dropped. Timers that fired or were dropped are
+        /// forgotten the next time the time driver processes its timers,
+        /// which it is woken to do right away. Runtimes without the time
+        /// driver always return `None`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration, Instant};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     let handle = runtime::Handle::current();
+        ///     let start = Instant::now();
+        ///     let sleep = time::sleep(Duration::from_secs(60));
+        ///
+        ///     let deadline = handle.next_timer_deadline().unwrap();
+        ///     assert_eq!(start + Duration::from_secs(60), deadline);
+        ///
+        ///     handle.advance_to(deadline).unwrap();
+        ///     sleep.await;
+        /// });
+        /// ```
+        ///
+        /// [`advance_to`]: method@Self::advance_to
+        pub fn next_timer_deadline(&self) -> Option<crate::time::Instant> {
+            self.time_handle
+                .as_ref()
+                .and_then(|time_handle| time_handle.next_deadline())
+        }
+
+        /// Returns the number of timers currently armed in the runtime's time
+        /// driver.
+        ///
+        /// While the clock is paused, this tells whether advancing it can
+        /// fire anything at all. Timers are counted from the moment the time
+        /// driver registers them until they fire or are dropped. The driver
+        /// registers new timers, and forgets dropped ones, the next time it
+        /// processes its queue, which it is woken to do right away, so let the
+        /// runtime park, for instance by yielding, before relying on the
+        /// count. Runtimes without the time driver always return `0`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     let handle = runtime::Handle::current();
+        ///     let sleep = time::sleep(Duration::from_secs(60));
+        ///
+        ///     tokio::task::yield_now().await;
+        ///     assert_eq!(1, handle.pending_timer_count());
+        ///
+        ///     drop(sleep);
+        ///     tokio::task::yield_now().await;
+        ///     assert_eq!(0, handle.pending_timer_count());
+        /// });
+        /// ```
+        pub fn pending_timer_count(&self) -> usize {
+            self.time_handle
+                .as_ref()
+                .map_or(0, |time_handle| time_handle.armed_timers())
+        }
+
+        /// Advance the runtime's paused clock to the next timer deadline and
+        /// fire that timer.
+        ///
+        /// This is an explicit, single step of the auto-advance performed by
+        /// the `test-util` clock: logical time moves forward to exactly the
+        /// earliest registered `Sleep` deadline, the timers due at that
+        /// deadline are fired, and the clock stays paused. The returned
+        /// future completes once the time driver has performed the step. If
+        /// no timer is registered, the clock is left unchanged.
+        ///
+        /// Timers are only registered once the task creating them has been
+        /// polled, so let that task run before stepping. Tasks of a
+        /// multi-thread runtime do not run while its clock is paused, so this
+        /// is mostly useful on a current-thread runtime.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built
+        /// with a pausable clock and [`ClockError::NotPaused`] if the clock
+        /// is not paused.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration, Instant};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     let start = Instant::now();
+        ///     let sleep = tokio::spawn(time::sleep(Duration::from_secs(60)));
+        ///     tokio::task::yield_now().await;
+        ///
+        ///     runtime::Handle::current().step().await.unwrap();
+        ///     sleep.await.unwrap();
+        ///
+        ///     assert!(start.elapsed() >= Duration::from_secs(60));
+        /// });
+        /// ```
+        ///
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+        pub async fn step(&self) -> Result<(), ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            if !self.clock.is_paused() {
+                return Err(ClockError::NotPaused);
+            }
+
+            if let Some(time_handle) = &self.time_handle {
+                time_handle.step().await;
+            }
+
+            Ok(())
+        }
+
+        /// Repeatedly advance the runtime's paused clock to the next timer
+        /// deadline and fire the timers due then, up to `n` times.
+        ///
+        /// This performs up to `n` [`step`]s, stopping early once no timer is
+        /// left to fire, and returns the total number of timers fired. Timers
+        /// sharing a deadline are fired by the same step. Tasks woken by a
+        /// step run before the next step is performed, so a sleep they start
+        /// is taken into account by the following steps, which makes this
+        /// convenient to drive a deterministic sequence of timers.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built
+        /// with a pausable clock and [`ClockError::NotPaused`] if the clock
+        /// is not paused.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     for secs in 1..=3 {
+        ///         tokio::spawn(time::sleep(Duration::from_secs(secs)));
+        ///     }
+        ///     tokio::task::yield_now().await;
+        ///
+        ///     let handle = runtime::Handle::current();
+        ///     assert_eq!(handle.advance_to_next_n(10).await, Ok(3));
+        /// });
+        /// ```
+        ///
+        /// [`step`]: method@Self::step
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+        pub async fn advance_to_next_n(&self, n: usize) -> Result<usize, ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            if !self.clock.is_paused() {
+                return Err(ClockError::NotPaused);
+            }
+
+            match &self.time_handle {
+                Some(time_handle) => Ok(time_handle.step_n(n).await),
+                None => Ok(0),
+            }
+        }
+
+        /// Advance the runtime's paused clock from timer to timer until no
+        /// timer is left, returning the clock's final instant.
+        ///
+        /// Where [`advance_time`] moves the clock by a fixed amount and may
+        /// leave later timers pending, this keeps performing [`step`]s until
+        /// a step finds no timer to fire. Tasks woken by a step run before
+        /// the next one, so timers they schedule in turn are drained as well:
+        /// this runs all the timed work scheduled on the runtime to
+        /// completion. The clock stays paused.
+        ///
+        /// The returned future never completes while a task keeps scheduling
+        /// new timers, as an [`Interval`] does.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built
+        /// with a pausable clock and [`ClockError::NotPaused`] if the clock
+        /// is not paused.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::{self, Duration, Instant};
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(true, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.block_on(async {
+        ///     let start = Instant::now();
+        ///
+        ///     tokio::spawn(async {
+        ///         time::sleep(Duration::from_secs(1)).await;
+        ///         time::sleep(Duration::from_secs(2)).await;
+        ///     });
+        ///     tokio::task::yield_now().await;
+        ///
+        ///     let end = runtime::Handle::current().drain_timers().await.unwrap();
+        ///     assert_eq!(start + Duration::from_secs(3), end);
+        /// });
+        /// ```
+        ///
+        /// [`advance_time`]: method@Self::advance_time
+        /// [`step`]: method@Self::step
+        /// [`Interval`]: crate::time::Interval
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+        pub async fn drain_timers(&self) -> Result<crate::time::Instant, ClockError> {
+            self.advance_to_next_n(usize::MAX).await?;
+            Ok(self.clock.now())
+        }
+
+        /// Pause the runtime's clock until the returned guard is dropped.
+        ///
+        /// This is a scoped alternative to [`pause_time`] and [`resume_time`]
+        /// that guarantees the clock is resumed even if the scope panics or
+        /// returns early.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the runtime was not built with a pausable clock.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// {
+        ///     let _guard = rt.handle().pause_scope();
+        ///     assert!(rt.handle().is_time_paused());
+        /// }
+        ///
+        /// assert!(!rt.handle().is_time_paused());
+        /// ```
+        ///
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`resume_time`]: method@Self::resume_time
+        pub fn pause_scope(&self) -> PauseGuard {
+            assert!(self.clock.pausable(), "the runtime's clock is not pausable");
+
+            PauseGuard::new(self.clock.clone())
+        }
+
+        /// Pause the runtime's clock and automatically resume it once
+        /// `duration` of real time has elapsed.
+        ///
+        /// This guards against a clock being left frozen forever. The
+        /// automatic resume is a task of the runtime waiting on a
+        /// [`sleep_realtime`], so it fires even though the runtime's own
+        /// timers are frozen, as long as the runtime is driven. If the clock
+        /// is resumed before then, the automatic resume does nothing, even if
+        /// the clock has been paused again in the meantime.
+        ///
+        /// Returns a [`PauseTimer`] with which to cancel the automatic resume,
+        /// or `Ok(None)` without scheduling one if the clock was already
+        /// paused.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the runtime's time driver is not enabled.
+        ///
+        /// [`sleep_realtime`]: crate::time::sleep_realtime
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        pub fn pause_for(
+            &self,
+            duration: std::time::Duration,
+        ) -> Result<Option<PauseTimer>, ClockError> {
+            assert!(
+                self.time_handle.is_some(),
+                "pause_for requires the time driver to be enabled"
+            );
+
+            if !self.pause_time()? {
+                // Balance the nested pause, the clock stays paused.
+                self.clock.resume();
+                return Ok(None);
+            }
+
+            let clock = self.clock.clone();
+            let generation = clock.pause_generation();
+
+            let task = self.spawn(async move {
+                crate::time::sleep_realtime(duration).await;
+                clock.resume_if_unchanged(generation);
+            });
+
+            Ok(Some(PauseTimer::new(task)))
+        }
+
+        /// Resume the runtime's clock.
+        ///
+        /// Balances one earlier call to [`pause_time`]. The clock only resumes
+        /// when the last outstanding pause is balanced.
+        ///
+        /// Returns `Ok(true)` if this call resumed the clock and `Ok(false)` if
+        /// the clock is still paused by an outer pause or was not paused.
+        ///
+        /// Like [`pause_time`], this may be called from any thread. Resuming
+        /// wakes the time driver, so timers whose deadline was reached while
+        /// the clock was paused, for instance through [`advance_time`], fire
+        /// without waiting for other activity of the runtime.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock.
+        ///
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`advance_time`]: method@Self::advance_time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        pub fn resume_time(&self) -> Result<bool, ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            self.clock.check_not_warping()?;
+            Ok(self.clock.resume())
+        }
+
+        /// Like [`resume_time`], also reporting how much real time the clock
+        /// spent in the pause that this call ended.
+        ///
+        /// Returns `Ok(Some(duration))` if this call resumed the clock, where
+        /// `duration` is the wall-clock time elapsed since it was paused, and
+        /// `Ok(None)` where [`resume_time`] would return `Ok(false)`. With
+        /// nested pauses, the pause runs from the outermost [`pause_time`] to
+        /// the resume that balances it.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Builder;
+        /// use std::time::Duration;
+        ///
+        /// let rt = Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .pausable_time(false, Duration::from_secs(0))
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.handle().pause_time().unwrap();
+        /// std::thread::sleep(Duration::from_millis(10));
+        ///
+        /// let frozen_for = rt.handle().resume_time_measured().unwrap().unwrap();
+        /// println!("clock frozen for {:?}", frozen_for);
+        /// assert!(frozen_for >= Duration::from_millis(10));
+        /// ```
+        ///
+        /// [`resume_time`]: method@Self::resume_time
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        pub fn resume_time_measured(&self) -> Result<Option<std::time::Duration>, ClockError> {
+            if !self.clock.pausable() {
+                return Err(ClockError::NotPausable);
+            }
+
+            self.clock.check_not_warping()?;
+            Ok(self.clock.resume_measured())
+        }
+
+        /// Advance the runtime's paused clock by `duration`, then resume it.
+        ///
+        /// This models skipping ahead and carrying on in a single operation.
+        /// Calling [`advance_time`] and then [`resume_time`] leaves a window in
+        /// which the clock is advanced but still frozen, and in which another
+        /// thread may pause, resume or advance it. Here, no other pause or
+        /// resume can happen in between.
+        ///
+        /// Like [`resume_time`], this balances one earlier call to
+        /// [`pause_time`], and the clock only resumes when the last
+        /// outstanding pause is balanced. The advance applies either way.
+        /// Returns `Ok(true)` if this call resumed the clock.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClockError::NotPausable`] if the runtime was not built with
+        /// a pausable clock, and [`ClockError::NotPaused`] without advancing
+        /// the clock if it is not paused. Returns [`ClockError::Overflow`],
+        /// leaving the clock paused and where it was, if advancing it by
+        /// `duration` would overflow it.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// let start = rt.block_on(async { tokio::time::Instant::now() });
+        ///
+        /// assert_eq!(rt.handle().resume_with_advance(Duration::from_secs(5)), Ok(true));
+        ///
+        /// rt.block_on(async move {
+        ///     assert!(start.elapsed() >= Duration::from_secs(5));
+        /// });
+        /// ```
+        ///
+        /// [`advance_time`]: method@Self::advance_time
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`resume_time`]: method@Self::resume_time
+        /// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+        /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+        /// [`ClockError::Overflow`]: crate::time::error::ClockError::Overflow
+        pub fn resume_with_advance(
+            &self,
+            duration: std::time::Duration,
+        ) -> Result<bool, ClockError> {
+            self.clock.resume_with_advance(duration)
+        }
+
+        /// Returns the number of calls to [`pause_time`] that have not yet been
+        /// balanced by a call to [`resume_time`].
+        ///
+        /// The clock is paused whenever this is non-zero. Runtimes that were
+        /// not built with a pausable clock always return zero.
+        ///
+        /// [`pause_time`]: method@Self::pause_time
+        /// [`resume_time`]: method@Self::resume_time
+        pub fn pause_depth(&self) -> usize {
+            if self.clock.pausable() {
+                self.clock.pause_depth()
+            } else {
+                0
+            }
+        }
+
+        /// Returns `true` if the runtime's clock is `clock`.
+        ///
+        /// This holds for runtimes built with [`Builder::clock`] given `clock`
+        /// or one of its clones, and lets code that hands a clock to a runtime
+        /// check that the runtime is actually driven by it.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use tokio::time::LocalClock;
+        ///
+        /// let clock = LocalClock::new(false);
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .clock(clock.clone())
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// assert!(rt.handle().uses_clock(&clock));
+        /// assert!(!rt.handle().uses_clock(&LocalClock::new(false)));
+        /// ```
+        ///
+        /// [`Builder::clock`]: crate::runtime::Builder::clock
+        pub fn uses_clock(&self, clock: &crate::time::LocalClock) -> bool {
+            self.clock.ptr_eq(clock.as_clock())
+        }
+
+        /// Returns counters of the pauses and resumes of the runtime's clock,
+        /// and of the total time it has spent paused.
+        ///
+        /// Runtimes that were not built with a pausable clock report zero for
+        /// every counter.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_current_thread()
+        ///     .enable_time()
+        ///     .start_paused(true)
+        ///     .build()
+        ///     .unwrap();
+        ///
+        /// rt.handle().resume_time().unwrap();
+        ///
+        /// let metrics = rt.handle().pause_metrics();
+        /// assert_eq!(metrics.pause_count(), 1);
+        /// assert_eq!(metrics.resume_count(), 1);
+        /// ```
+        pub fn pause_metrics(&self) -> crate::runtime::PauseMetrics {
+            self.clock.pause_metrics()
+        }
     }
 }
 
@@ -196,7 +196,25 @@ cfg_rt! {
     pub(crate) mod driver;
 
     mod pausable_time_config;
-    use pausable_time_config::PausableTimeConfig;
+
+    cfg_time! {
+        pub use pausable_time_config::PausableTimeConfig;
+    }
+
+    cfg_not_time! {
+        use pausable_time_config::PausableTimeConfig;
+    }
+
+    mod quiesce;
+    use quiesce::Quiesce;
+
+    cfg_time! {
+        mod pausable_runtime;
+        pub use pausable_runtime::{PausableHandle, PausableRuntime};
+
+        mod pause_metrics;
+        pub use pause_metrics::PauseMetrics;
+    }
     use self::enter::enter;
 
     mod handle;
@@ -221,6 +239,7 @@ cfg_rt_multi_thread! {
 
 cfg_rt! {
     use crate::task::JoinHandle;
+    use crate::util::Callback;
     use std::sync::atomic::Ordering;
 
     use std::future::Future;
@@ -287,9 +306,6 @@ cfg_rt! {
         ThreadPool(ThreadPool),
     }
 
-    /// After thread starts / before thread stops
-    type Callback = std::sync::Arc<dyn Fn() + Send + Sync>;
-
     impl Runtime {
         /// Create a new runtime instance with default configuration values.
         ///
@@ -565,8 +581,9 @@ cfg_rt! {
         }
 
 
-        /// Get the elapsed millis according to the pausable clock. This
-        /// function will panic if the runtime is not pausable
+        /// Get the elapsed millis according to the pausable clock. For a
+        /// runtime that is not pausable, this is the real time elapsed since
+        /// it was built.
         pub fn elapsed_millis(&self) -> u64 {
             self.handle.clock.elapsed_millis()
         }
@@ -582,6 +599,11 @@ cfg_rt! {
         }
 
         /// Is the runtime paused using the given atomic ordering?
+        ///
+        /// See [`time::is_paused_ordered`] for the implications of each
+        /// ordering.
+        ///
+        /// [`time::is_paused_ordered`]: crate::time::is_paused_ordered
         pub fn is_paused_ordered(&self, ordering: Ordering) -> bool {
             self.handle.clock.is_paused_ordered(ordering)
         }
@@ -597,6 +619,12 @@ cfg_rt! {
             self.handle.clock.wait_for_resume()
         }
 
+        /// Block _synchronously_ until the runtime resumes or `timeout` of real
+        /// time elapses. Returns `false` if the timeout elapsed first.
+        pub fn wait_for_resume_timeout(&self, timeout: std::time::Duration) -> bool {
+            self.handle.clock.wait_for_resume_timeout(timeout)
+        }
+
         /// Block _synchronously_ until the runtime pauses (if it's resumed)
         pub fn wait_for_pause(&self) {
             self.handle.clock.wait_for_pause()
@@ -629,11 +657,19 @@ cfg_rt! {
 
         /// Evaluate the given closure if the clock is resumed, and prevent resuming
         /// until the closure completes. If the clock is resumed, this method will
-        /// block _synchronously_ until the clock is paused
+        /// block _synchronously_ until the clock is paused.
+        ///
+        /// Panics if the clock is not pausable or does not support this
+        /// operation, see [`time::run_unresumable`] for a fallible version.
+        ///
+        /// [`time::run_unresumable`]: crate::time::run_unresumable
         pub fn run_unresumable<T,F>(&self, action: F) -> T
             where F : FnOnce() -> T
         {
-            self.handle.clock.run_unresumable(action)
+            match self.handle.clock.run_unresumable(action) {
+                Ok(output) => output,
+                Err(e) => panic!("{}", e),
+            }
         }
 
         /// Run the provided function on an executor dedicated to blocking operations.
@@ -0,0 +1,182 @@
+use crate::runtime::{EnterGuard, Handle, Runtime};
+use crate::task::JoinHandle;
+use crate::time::error::ClockError;
+
+use std::future::Future;
+use std::time::Duration;
+
+/// A runtime whose clock is known to be pausable.
+///
+/// Returned by [`Builder::build_pausable`]. The pause related methods of its
+/// [`PausableHandle`] cannot fail for lack of a pausable clock, so code that
+/// needs to pause time can require a `PausableRuntime` or a `PausableHandle`
+/// and have the compiler check that it gets one, instead of handling
+/// [`ClockError::NotPausable`] at runtime.
+///
+/// A `PausableRuntime` is otherwise an ordinary [`Runtime`], which can be
+/// borrowed with [`runtime`] or recovered with [`into_runtime`].
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .build_pausable()
+///     .unwrap();
+///
+/// assert!(rt.handle().pause());
+///
+/// rt.block_on(async {
+///     assert!(tokio::time::is_paused());
+/// });
+///
+/// assert!(rt.handle().resume());
+/// ```
+///
+/// [`Builder::build_pausable`]: crate::runtime::Builder::build_pausable
+/// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+/// [`runtime`]: method@Self::runtime
+/// [`into_runtime`]: method@Self::into_runtime
+#[derive(Debug)]
+pub struct PausableRuntime {
+    runtime: Runtime,
+    handle: PausableHandle,
+}
+
+/// Handle to a runtime whose clock is known to be pausable.
+///
+/// Obtained from [`PausableRuntime::handle`], or from an ordinary [`Handle`]
+/// with [`Handle::pausable`]. Like a [`Handle`], it is cheap to clone and can
+/// be used from any thread.
+///
+/// [`Handle::pausable`]: crate::runtime::Handle::pausable
+#[derive(Debug, Clone)]
+pub struct PausableHandle {
+    handle: Handle,
+}
+
+impl PausableRuntime {
+    pub(crate) fn new(runtime: Runtime) -> PausableRuntime {
+        let handle = PausableHandle {
+            handle: runtime.handle().clone(),
+        };
+
+        PausableRuntime { runtime, handle }
+    }
+
+    /// Returns a handle to the runtime's pausable clock and spawner.
+    pub fn handle(&self) -> &PausableHandle {
+        &self.handle
+    }
+
+    /// Returns the underlying runtime.
+    pub fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+
+    /// Consumes the `PausableRuntime`, returning the underlying runtime.
+    pub fn into_runtime(self) -> Runtime {
+        self.runtime
+    }
+
+    /// Runs a future to completion on the runtime.
+    ///
+    /// See [`Runtime::block_on`].
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Spawns a future onto the runtime.
+    ///
+    /// See [`Runtime::spawn`].
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.runtime.spawn(future)
+    }
+
+    /// Enters the runtime context.
+    ///
+    /// See [`Runtime::enter`].
+    pub fn enter(&self) -> EnterGuard<'_> {
+        self.runtime.enter()
+    }
+}
+
+impl PausableHandle {
+    /// Wraps `handle` if its runtime has a pausable clock.
+    pub(crate) fn new(handle: &Handle) -> Option<PausableHandle> {
+        if handle.clock.pausable() {
+            Some(PausableHandle {
+                handle: handle.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Pauses the runtime's clock, returning `true` if this call paused it.
+    ///
+    /// Pauses nest like those of [`Handle::pause_time`], which this is the
+    /// infallible equivalent of.
+    ///
+    /// [`Handle::pause_time`]: crate::runtime::Handle::pause_time
+    pub fn pause(&self) -> bool {
+        self.handle.clock.pause()
+    }
+
+    /// Balances one call to [`pause`], returning `true` if the clock is
+    /// running again as a result.
+    ///
+    /// [`pause`]: method@Self::pause
+    pub fn resume(&self) -> bool {
+        self.handle.clock.resume()
+    }
+
+    /// Like [`resume`], returning the real time spent in the pause that this
+    /// call ended, or `None` if the clock is not running again.
+    ///
+    /// See [`Handle::resume_time_measured`].
+    ///
+    /// [`resume`]: method@Self::resume
+    /// [`Handle::resume_time_measured`]: crate::runtime::Handle::resume_time_measured
+    pub fn resume_measured(&self) -> Option<Duration> {
+        self.handle.clock.resume_measured()
+    }
+
+    /// Returns `true` if the runtime's clock is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.handle.clock.is_paused()
+    }
+
+    /// Returns the number of calls to [`pause`] that have not yet been
+    /// balanced by a call to [`resume`].
+    ///
+    /// [`pause`]: method@Self::pause
+    /// [`resume`]: method@Self::resume
+    pub fn pause_depth(&self) -> usize {
+        self.handle.clock.pause_depth()
+    }
+
+    /// Advances the runtime's paused clock by `duration`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClockError::NotPaused`] without advancing the clock if it is
+    /// not paused.
+    ///
+    /// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+    pub fn advance(&self, duration: Duration) -> Result<(), ClockError> {
+        self.handle.clock.try_advance(duration)
+    }
+
+    /// Returns the underlying runtime handle.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
@@ -1,7 +1,41 @@
 use std::time::Duration;
 
+/// How a runtime's pausable clock is configured.
+///
+/// Returned by [`Builder::time_config`], so that the configuration a
+/// [`Builder`] will apply can be checked without building a runtime.
+///
+/// [`Builder`]: crate::runtime::Builder
+/// [`Builder::time_config`]: crate::runtime::Builder::time_config
 #[derive(Debug, Copy, Clone, Default)]
-pub(crate) struct PausableTimeConfig {
+pub struct PausableTimeConfig {
     pub(crate) start_paused: bool,
     pub(crate) elapsed_time: Duration,
+    pub(crate) time_scale: Option<f64>,
+    pub(crate) paused_duration: Duration,
+}
+
+impl PausableTimeConfig {
+    /// Returns `true` if the clock starts paused.
+    pub fn start_paused(&self) -> bool {
+        self.start_paused
+    }
+
+    /// Returns the time the clock reports as already elapsed when the
+    /// runtime starts.
+    pub fn elapsed_time(&self) -> Duration {
+        self.elapsed_time
+    }
+
+    /// Returns the rate at which the clock runs relative to real time, if
+    /// one was set.
+    pub fn time_scale(&self) -> Option<f64> {
+        self.time_scale
+    }
+
+    /// Returns the real time the clock reports having spent paused before
+    /// the runtime starts.
+    pub fn paused_duration(&self) -> Duration {
+        self.paused_duration
+    }
 }
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+/// Counters describing how often and for how long a runtime's clock has been
+/// paused.
+///
+/// Returned by [`Handle::pause_metrics`]. The counters are read together, so
+/// `pause_count` is either equal to `resume_count` or exceeds it by one while
+/// the clock is paused. Runtimes that were not built with a pausable clock
+/// are never paused, and report zero for every counter.
+///
+/// Only transitions of the clock are counted: a nested pause of a clock that
+/// is already paused, and the resume balancing it, are not.
+///
+/// [`Handle::pause_metrics`]: crate::runtime::Handle::pause_metrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PauseMetrics {
+    pub(crate) pause_count: u64,
+    pub(crate) resume_count: u64,
+    pub(crate) total_paused_duration: Duration,
+}
+
+impl PauseMetrics {
+    /// Returns the number of times the clock has been paused, including the
+    /// initial pause of a runtime built to start paused.
+    pub fn pause_count(&self) -> u64 {
+        self.pause_count
+    }
+
+    /// Returns the number of times the clock has been resumed.
+    pub fn resume_count(&self) -> u64 {
+        self.resume_count
+    }
+
+    /// Returns the total real time the clock has spent paused, including the
+    /// current pause if the clock is paused.
+    pub fn total_paused_duration(&self) -> Duration {
+        self.total_paused_duration
+    }
+}
@@ -0,0 +1,146 @@
+//! Detection of the moments where the clock is paused and no worker of the
+//! runtime is polling a task.
+//!
+//! Workers report when they start and stop driving the scheduler, and when
+//! they park for lack of work. The clock reports its pauses and resumes. Once
+//! the clock is paused and every worker is parked, the callback registered
+//! with `Builder::on_quiesced_pause` runs, at most once per pause.
+//!
+//! There is no timeout: a task that never yields keeps its worker active, and
+//! the callback is then simply not run for that pause. A pause that ends
+//! before the runtime quiesces is forgotten.
+
+use crate::loom::sync::{Arc, Mutex};
+use crate::runtime::Callback;
+
+use std::fmt;
+
+/// Tracks the quiescence of a runtime's workers. Does nothing when no
+/// callback is registered.
+#[derive(Clone)]
+pub(crate) struct Quiesce {
+    inner: Option<Arc<Inner>>,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    on_quiesced_pause: Callback,
+}
+
+#[derive(Default)]
+struct State {
+    /// Number of workers driving the scheduler that are not parked.
+    active: usize,
+
+    /// Whether the clock is paused.
+    paused: bool,
+
+    /// Whether the callback is still due for the current pause.
+    pending: bool,
+}
+
+/// Marks a worker as driving the scheduler until dropped.
+pub(crate) struct Active {
+    inner: Option<Arc<Inner>>,
+}
+
+impl Quiesce {
+    pub(crate) fn new(on_quiesced_pause: Option<Callback>) -> Quiesce {
+        Quiesce {
+            inner: on_quiesced_pause.map(|on_quiesced_pause| {
+                Arc::new(Inner {
+                    state: Mutex::new(State::default()),
+                    on_quiesced_pause,
+                })
+            }),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Called by a worker before it starts driving the scheduler.
+    pub(crate) fn enter(&self) -> Active {
+        if let Some(inner) = &self.inner {
+            inner.state.lock().active += 1;
+        }
+
+        Active {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Called by a worker right before it parks for lack of work.
+    ///
+    /// If this makes the runtime quiescent while the clock is paused, the
+    /// callback runs on the calling worker before it parks.
+    pub(crate) fn before_park(&self) {
+        if let Some(inner) = &self.inner {
+            let mut state = inner.state.lock();
+            state.active -= 1;
+            inner.run_if_quiesced(&mut state);
+        }
+    }
+
+    /// Called by a worker right after it is unparked.
+    ///
+    /// Blocks while the callback runs, so that no worker polls a task before
+    /// it returns.
+    pub(crate) fn after_unpark(&self) {
+        if let Some(inner) = &self.inner {
+            inner.state.lock().active += 1;
+        }
+    }
+
+    /// Called each time the clock is paused.
+    pub(crate) fn paused(&self) {
+        if let Some(inner) = &self.inner {
+            let mut state = inner.state.lock();
+            state.paused = true;
+            state.pending = true;
+            inner.run_if_quiesced(&mut state);
+        }
+    }
+
+    /// Called each time the clock is resumed.
+    pub(crate) fn resumed(&self) {
+        if let Some(inner) = &self.inner {
+            let mut state = inner.state.lock();
+            state.paused = false;
+            state.pending = false;
+        }
+    }
+}
+
+impl Inner {
+    /// Runs the callback, with the state locked, if it is due.
+    fn run_if_quiesced(&self, state: &mut State) {
+        if state.active == 0 && state.paused && state.pending {
+            state.pending = false;
+            (self.on_quiesced_pause)();
+        }
+    }
+}
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        if let Some(inner) = &self.inner {
+            let mut state = inner.state.lock();
+            state.active -= 1;
+
+            // Do not risk a double panic while unwinding out of the scheduler.
+            if !std::thread::panicking() {
+                inner.run_if_quiesced(&mut state);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Quiesce {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Quiesce")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
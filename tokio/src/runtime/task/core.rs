@@ -10,15 +10,17 @@
 //! use.
 
 use crate::loom::cell::UnsafeCell;
+use crate::loom::sync::atomic::AtomicU8;
 use crate::runtime::task::raw::{self, Vtable};
 use crate::runtime::task::state::State;
 use crate::runtime::task::waker::waker_ref;
-use crate::runtime::task::{Notified, Schedule, Task};
+use crate::runtime::task::{CancelReason, Id, Notified, Schedule, Task};
 use crate::util::linked_list;
 
 use std::future::Future;
 use std::pin::Pin;
 use std::ptr::NonNull;
+use std::sync::atomic::Ordering::{AcqRel, Acquire};
 use std::task::{Context, Poll, Waker};
 
 /// The task cell. Contains the components of the task.
@@ -64,6 +66,13 @@ pub(crate) struct Header {
 
     /// Table of function pointers for executing actions on the task.
     pub(super) vtable: &'static Vtable,
+
+    /// Unique ID of the task.
+    pub(super) id: Id,
+
+    /// Why the task was cancelled, recorded by the first request to cancel
+    /// it. Zero until then.
+    cancel_reason: AtomicU8,
 }
 
 unsafe impl Send for Header {}
@@ -93,6 +102,8 @@ impl<T: Future, S: Schedule> Cell<T, S> {
                 queue_next: UnsafeCell::new(None),
                 stack_next: UnsafeCell::new(None),
                 vtable: raw::vtable::<T, S>(),
+                id: Id::next(),
+                cancel_reason: AtomicU8::new(0),
             },
             core: Core {
                 scheduler: UnsafeCell::new(None),
@@ -269,13 +280,44 @@ impl<T: Future, S: Schedule> Core<T, S> {
     }
 }
 
+impl Header {
+    /// Records why the task is about to be cancelled, unless a reason was
+    /// already recorded by an earlier request.
+    pub(super) fn set_cancel_reason(&self, reason: CancelReason) {
+        let code = match reason {
+            CancelReason::Aborted => 1,
+            CancelReason::RuntimeShutdown => 2,
+            CancelReason::Timeout => 3,
+        };
+
+        let _ = self
+            .cancel_reason
+            .compare_exchange(0, code, AcqRel, Acquire);
+    }
+
+    /// Returns why the task was cancelled.
+    pub(super) fn cancel_reason(&self) -> CancelReason {
+        match self.cancel_reason.load(Acquire) {
+            1 => CancelReason::Aborted,
+            2 => CancelReason::RuntimeShutdown,
+            3 => CancelReason::Timeout,
+            code => {
+                // Tasks are only cancelled through `RawTask::shutdown`, which
+                // records a reason first.
+                debug_assert!(false, "task cancelled without a reason; code={}", code);
+                CancelReason::RuntimeShutdown
+            }
+        }
+    }
+}
+
 cfg_rt_multi_thread! {
     impl Header {
         pub(crate) fn shutdown(&self) {
             use crate::runtime::task::RawTask;
 
             let task = unsafe { RawTask::from_raw(self.into()) };
-            task.shutdown();
+            task.shutdown(super::CancelReason::RuntimeShutdown);
         }
     }
 }
@@ -1,44 +1,460 @@
+use crate::runtime::task::Id;
+
 use std::any::Any;
+use std::error::Error;
 use std::fmt;
 use std::io;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 cfg_rt! {
     /// Task failed to execute to completion.
     pub struct JoinError {
         repr: Repr,
+        id: Id,
+    }
+
+    /// Why a task was cancelled.
+    ///
+    /// Returned by [`JoinError::cancel_reason`].
+    ///
+    /// [`JoinError::cancel_reason`]: crate::task::JoinError::cancel_reason
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum CancelReason {
+        /// The task was aborted with [`JoinHandle::abort`].
+        ///
+        /// [`JoinHandle::abort`]: crate::task::JoinHandle::abort
+        Aborted,
+
+        /// The runtime or `LocalSet` running the task shut down before the
+        /// task completed.
+        RuntimeShutdown,
+
+        /// The task was cancelled because a timeout elapsed.
+        ///
+        /// Tokio itself never cancels a task on a timeout; this is reported
+        /// by errors built with [`JoinError::cancelled_with`] by code that
+        /// does.
+        ///
+        /// [`JoinError::cancelled_with`]: crate::task::JoinError::cancelled_with
+        Timeout,
+    }
+
+    /// How a task failed to execute to completion.
+    ///
+    /// Returned by [`JoinError::into_outcome`].
+    ///
+    /// [`JoinError::into_outcome`]: crate::task::JoinError::into_outcome
+    pub enum TaskOutcome {
+        /// The task was cancelled. See [`JoinError::cancel_reason`] for why.
+        ///
+        /// [`JoinError::cancel_reason`]: crate::task::JoinError::cancel_reason
+        Cancelled,
+
+        /// The task panicked, with the given payload.
+        Panicked(Box<dyn Any + Send + 'static>),
+
+        /// The task panicked, but its payload is still shared with a copy
+        /// made by [`JoinError::clone_shallow`], so it cannot be moved out.
+        ///
+        /// The error is handed back, so that the payload can be taken with
+        /// [`JoinError::try_into_panic`] once the copies are dropped.
+        ///
+        /// [`JoinError::clone_shallow`]: crate::task::JoinError::clone_shallow
+        /// [`JoinError::try_into_panic`]: crate::task::JoinError::try_into_panic
+        PanickedShared(JoinError),
     }
 }
 
 enum Repr {
-    Cancelled,
-    Panic(Mutex<Box<dyn Any + Send + 'static>>),
+    Cancelled(CancelReason),
+
+    /// The payload is shared with the errors made by `clone_shallow`.
+    Panic(Arc<Mutex<Box<dyn Any + Send + 'static>>>),
+
+    /// A panic whose payload is a `Box<dyn Error + Send + Sync>`, kept
+    /// unwrapped so that it can be returned by `Error::source`.
+    PanicError(Arc<Box<dyn Error + Send + Sync + 'static>>),
 }
 
 impl JoinError {
-    pub(crate) fn cancelled() -> JoinError {
+    /// Creates an error reporting that the task identified by `id` was
+    /// cancelled for `reason`.
+    ///
+    /// This is useful to code driving tasks of its own, such as a wrapper
+    /// around [`JoinHandle`], that needs to report a cancellation the same
+    /// way Tokio does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::task::{CancelReason, JoinError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let handle = tokio::spawn(async {});
+    ///     let err = JoinError::cancelled_with(handle.id(), CancelReason::Aborted);
+    ///
+    ///     assert!(err.is_cancelled());
+    ///     assert_eq!(Some(CancelReason::Aborted), err.cancel_reason());
+    ///     assert_eq!(handle.id(), err.id());
+    /// }
+    /// ```
+    ///
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    pub fn cancelled_with(id: Id, reason: CancelReason) -> JoinError {
         JoinError {
-            repr: Repr::Cancelled,
+            repr: Repr::Cancelled(reason),
+            id,
         }
     }
 
-    pub(crate) fn panic(err: Box<dyn Any + Send + 'static>) -> JoinError {
-        JoinError {
-            repr: Repr::Panic(Mutex::new(err)),
-        }
+    pub(crate) fn panic(id: Id, err: Box<dyn Any + Send + 'static>) -> JoinError {
+        let repr = match err.downcast::<Box<dyn Error + Send + Sync + 'static>>() {
+            Ok(error) => Repr::PanicError(Arc::new(*error)),
+            Err(err) => Repr::Panic(Arc::new(Mutex::new(err))),
+        };
+
+        JoinError { repr, id }
+    }
+
+    /// Returns a copy of the error sharing its panic payload, if any.
+    ///
+    /// The copy reports the same [`is_cancelled`], [`is_panic`] and [`id`],
+    /// and displays the same panic message, so that one failure can be
+    /// reported to several consumers. The payload itself is not cloned, as
+    /// panic payloads are not `Clone`: all copies refer to the same one.
+    ///
+    /// `JoinError` does not implement `Clone` because [`into_panic`] can only
+    /// hand out the payload once it is no longer shared. Making each copy
+    /// explicit keeps that visible where the error is duplicated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::{self, Duration};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let handle = tokio::spawn(time::sleep(Duration::from_secs(10)));
+    ///     handle.abort();
+    ///
+    ///     let err = handle.await.unwrap_err();
+    ///     let copy = err.clone_shallow();
+    ///
+    ///     assert!(copy.is_cancelled());
+    ///     assert_eq!(err.id(), copy.id());
+    /// }
+    /// ```
+    ///
+    /// [`is_cancelled`]: method@Self::is_cancelled
+    /// [`is_panic`]: method@Self::is_panic
+    /// [`id`]: method@Self::id
+    /// [`into_panic`]: method@Self::into_panic
+    pub fn clone_shallow(&self) -> JoinError {
+        let repr = match &self.repr {
+            Repr::Cancelled(reason) => Repr::Cancelled(*reason),
+            Repr::Panic(p) => Repr::Panic(p.clone()),
+            Repr::PanicError(e) => Repr::PanicError(e.clone()),
+        };
+
+        JoinError { repr, id: self.id }
     }
 
     /// Returns true if the error was caused by the task being cancelled
     pub fn is_cancelled(&self) -> bool {
-        matches!(&self.repr, Repr::Cancelled)
+        matches!(&self.repr, Repr::Cancelled(_))
+    }
+
+    /// Returns why the task was cancelled, or `None` if it was not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::task::CancelReason;
+    /// use tokio::time::{self, Duration};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let handle = tokio::spawn(time::sleep(Duration::from_secs(10)));
+    ///     handle.abort();
+    ///
+    ///     let err = handle.await.unwrap_err();
+    ///     assert_eq!(Some(CancelReason::Aborted), err.cancel_reason());
+    /// }
+    /// ```
+    pub fn cancel_reason(&self) -> Option<CancelReason> {
+        match &self.repr {
+            Repr::Cancelled(reason) => Some(*reason),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the error was caused by the task panicking
+    ///
+    /// A panic raised while a task is polled propagates to the runtime rather
+    /// than being reported here, so this is the case when dropping the task's
+    /// future panicked as the task was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # struct PanicOnDrop;
+    /// # impl Drop for PanicOnDrop {
+    /// #     fn drop(&mut self) {
+    /// #         panic!("boom");
+    /// #     }
+    /// # }
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+    ///
+    ///     let handle = tokio::spawn(async move {
+    ///         let _guard = PanicOnDrop;
+    ///         let _ = rx.await;
+    ///     });
+    ///
+    ///     // Let the task start, then cancel it so the guard is dropped.
+    ///     tokio::task::yield_now().await;
+    ///     handle.abort();
+    ///
+    ///     let err = handle.await.unwrap_err();
+    ///     assert!(err.is_panic());
+    /// }
+    /// ```
+    pub fn is_panic(&self) -> bool {
+        matches!(&self.repr, Repr::Panic(_) | Repr::PanicError(_))
+    }
+
+    /// Consumes the join error, returning the object with which the task panicked.
+    ///
+    /// # Panics
+    ///
+    /// `into_panic()` panics if the `Error` does not represent the underlying
+    /// task terminating with a panic, or if the panic payload is still shared
+    /// with a copy made by [`clone_shallow`]. Use `is_cancelled` to check the
+    /// error reason or [`try_into_panic`] for a variant that does not panic.
+    ///
+    /// # Shared payloads
+    ///
+    /// Panic payloads are not `Clone`, so a shared payload can only be moved
+    /// out once the copies sharing it are dropped. Handing out a substitute
+    /// in the meantime, such as a copy of the panic message, would give back
+    /// a value of a different type than the task panicked with: a `downcast`
+    /// to the original type would fail and `resume_unwind` would propagate
+    /// the wrong payload, without any sign that it was substituted. This
+    /// panics instead, and code that shares errors should call
+    /// [`try_into_panic`], which hands the error back while it is shared.
+    ///
+    /// [`clone_shallow`]: method@Self::clone_shallow
+    /// [`try_into_panic`]: method@Self::try_into_panic
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use std::panic;
+    ///
+    /// # struct PanicOnDrop;
+    /// # impl Drop for PanicOnDrop {
+    /// #     fn drop(&mut self) {
+    /// #         panic!("boom");
+    /// #     }
+    /// # }
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+    ///
+    ///     let handle = tokio::spawn(async move {
+    ///         let _guard = PanicOnDrop;
+    ///         let _ = rx.await;
+    ///     });
+    ///
+    ///     // Let the task start, then cancel it so the guard is dropped.
+    ///     tokio::task::yield_now().await;
+    ///     handle.abort();
+    ///
+    ///     let err = handle.await.unwrap_err();
+    ///     if !err.is_cancelled() {
+    ///         // Resume the panic on the main task
+    ///         panic::resume_unwind(err.into_panic());
+    ///     }
+    /// }
+    /// ```
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        self.try_into_panic()
+            .expect("`JoinError` reason is not a panic, or its payload is shared.")
+    }
+
+    /// Consumes the join error, returning the object with which the task
+    /// panicked if the task terminated due to a panic. Otherwise, `self` is
+    /// returned.
+    ///
+    /// `self` is also returned while the payload is shared with a copy made
+    /// by [`clone_shallow`], as the payload can then not be moved out. Once
+    /// the copies are dropped, calling this again returns the payload.
+    ///
+    /// [`clone_shallow`]: method@Self::clone_shallow
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use std::panic;
+    ///
+    /// # struct PanicOnDrop;
+    /// # impl Drop for PanicOnDrop {
+    /// #     fn drop(&mut self) {
+    /// #         panic!("boom");
+    /// #     }
+    /// # }
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+    ///
+    ///     let handle = tokio::spawn(async move {
+    ///         let _guard = PanicOnDrop;
+    ///         let _ = rx.await;
+    ///     });
+    ///
+    ///     // Let the task start, then cancel it so the guard is dropped.
+    ///     tokio::task::yield_now().await;
+    ///     handle.abort();
+    ///
+    ///     let err = handle.await.unwrap_err();
+    ///     if let Ok(reason) = err.try_into_panic() {
+    ///         // Resume the panic on the main task
+    ///         panic::resume_unwind(reason);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send + 'static>, JoinError> {
+        match self.repr {
+            Repr::Panic(p) => match Arc::try_unwrap(p) {
+                Ok(p) => Ok(p.into_inner().expect("Extracting panic from mutex")),
+                Err(p) => Err(JoinError {
+                    repr: Repr::Panic(p),
+                    id: self.id,
+                }),
+            },
+            Repr::PanicError(e) => match Arc::try_unwrap(e) {
+                Ok(e) => Ok(Box::new(e)),
+                Err(e) => Err(JoinError {
+                    repr: Repr::PanicError(e),
+                    id: self.id,
+                }),
+            },
+            _ => Err(self),
+        }
+    }
+
+    /// Consumes the join error, returning how the task failed along with the
+    /// panic payload, if any.
+    ///
+    /// This gives a single place to handle both cases, where checking
+    /// [`is_cancelled`] and then calling [`into_panic`] takes two.
+    ///
+    /// This never panics. While the panic payload is still shared with a copy
+    /// made by [`clone_shallow`], the error is returned as
+    /// [`TaskOutcome::PanickedShared`] rather than the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::task::TaskOutcome;
+    /// use tokio::time::{self, Duration};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let handle = tokio::spawn(time::sleep(Duration::from_secs(10)));
+    ///     handle.abort();
+    ///
+    ///     match handle.await.unwrap_err().into_outcome() {
+    ///         TaskOutcome::Cancelled => println!("task was cancelled"),
+    ///         TaskOutcome::Panicked(payload) => std::panic::resume_unwind(payload),
+    ///         TaskOutcome::PanickedShared(err) => println!("task failed: {}", err),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`is_cancelled`]: method@Self::is_cancelled
+    /// [`into_panic`]: method@Self::into_panic
+    /// [`clone_shallow`]: method@Self::clone_shallow
+    /// [`TaskOutcome::PanickedShared`]: crate::task::TaskOutcome::PanickedShared
+    pub fn into_outcome(self) -> TaskOutcome {
+        if self.is_cancelled() {
+            return TaskOutcome::Cancelled;
+        }
+
+        match self.try_into_panic() {
+            Ok(payload) => TaskOutcome::Panicked(payload),
+            Err(err) => TaskOutcome::PanickedShared(err),
+        }
+    }
+
+    /// Converts the join error into an [`io::Error`] of kind
+    /// [`ErrorKind::Other`].
+    ///
+    /// If the task panicked with a string message, as produced by `panic!`,
+    /// the message is included in the error. This is what the
+    /// `From<JoinError>` conversion to `io::Error` uses.
+    ///
+    /// [`io::Error`]: std::io::Error
+    /// [`ErrorKind::Other`]: std::io::ErrorKind::Other
+    pub fn into_io_error(self) -> io::Error {
+        let msg = match self.repr {
+            Repr::Cancelled(_) => "task was cancelled".to_string(),
+            Repr::Panic(p) => {
+                let payload = p.lock().expect("Extracting panic from mutex");
+
+                match panic_payload_as_str(&**payload) {
+                    Some(msg) => format!("task panicked: {}", msg),
+                    None => "task panicked".to_string(),
+                }
+            }
+            Repr::PanicError(e) => format!("task panicked: {}", e),
+        };
+
+        io::Error::new(io::ErrorKind::Other, msg)
+    }
+
+    /// Returns the [`Id`] of the task that failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::{self, Duration};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let handle = tokio::spawn(time::sleep(Duration::from_secs(10)));
+    ///     let id = handle.id();
+    ///
+    ///     handle.abort();
+    ///     let err = handle.await.unwrap_err();
+    ///
+    ///     assert_eq!(id, err.id());
+    /// }
+    /// ```
+    ///
+    /// [`Id`]: crate::task::Id
+    pub fn id(&self) -> Id {
+        self.id
     }
 }
 
 impl fmt::Display for JoinError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.repr {
-            Repr::Cancelled => write!(fmt, "cancelled"),
-            Repr::Panic(_) => write!(fmt, "panic"),
+            Repr::Cancelled(_) => write!(fmt, "cancelled"),
+            Repr::Panic(p) => match p
+                .lock()
+                .ok()
+                .as_deref()
+                .and_then(|payload| panic_payload_as_str(&**payload))
+            {
+                Some(msg) => write!(fmt, "panicked: {:?}", msg),
+                None => write!(fmt, "panic"),
+            },
+            Repr::PanicError(e) => write!(fmt, "panicked: {}", e),
         }
     }
 }
@@ -46,22 +462,53 @@ impl fmt::Display for JoinError {
 impl fmt::Debug for JoinError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.repr {
-            Repr::Cancelled => write!(fmt, "JoinError::Cancelled"),
-            Repr::Panic(_) => write!(fmt, "JoinError::Panic(...)"),
+            Repr::Cancelled(reason) => {
+                write!(fmt, "JoinError::Cancelled({:?}, {:?})", self.id, reason)
+            }
+            Repr::Panic(_) | Repr::PanicError(_) => {
+                write!(fmt, "JoinError::Panic({:?}, ...)", self.id)
+            }
         }
     }
 }
 
-impl std::error::Error for JoinError {}
+impl fmt::Debug for TaskOutcome {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskOutcome::Cancelled => write!(fmt, "TaskOutcome::Cancelled"),
+            TaskOutcome::Panicked(_) => write!(fmt, "TaskOutcome::Panicked(...)"),
+            TaskOutcome::PanickedShared(err) => {
+                write!(fmt, "TaskOutcome::PanickedShared({:?})", err)
+            }
+        }
+    }
+}
+
+impl Error for JoinError {
+    /// Returns the error the task panicked with, if its panic payload was a
+    /// `Box<dyn Error + Send + Sync>`.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.repr {
+            Repr::PanicError(e) => Some(&***e),
+            _ => None,
+        }
+    }
+}
 
 impl From<JoinError> for io::Error {
     fn from(src: JoinError) -> io::Error {
-        io::Error::new(
-            io::ErrorKind::Other,
-            match src.repr {
-                Repr::Cancelled => "task was cancelled",
-                Repr::Panic(_) => "task panicked",
-            },
-        )
+        src.into_io_error()
+    }
+}
+
+/// Returns the panic message if the payload is a `&str` or a `String`, which
+/// covers the payloads produced by `panic!`.
+fn panic_payload_as_str(payload: &(dyn Any + Send + 'static)) -> Option<&str> {
+    if let Some(s) = payload.downcast_ref::<&'static str>() {
+        Some(s)
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        Some(s)
+    } else {
+        None
     }
 }
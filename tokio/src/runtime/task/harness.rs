@@ -102,7 +102,7 @@ where
             // If the task is cancelled, avoid polling it, instead signalling it
             // is complete.
             if snapshot.is_cancelled() {
-                Poll::Ready(Err(JoinError::cancelled()))
+                Poll::Ready(Err(self.cancelled()))
             } else {
                 let res = guard.core.poll(self.header());
 
@@ -284,6 +284,11 @@ where
 
     // ====== internal ======
 
+    /// Returns the error reporting why the task was cancelled.
+    fn cancelled(&self) -> JoinError {
+        JoinError::cancelled_with(self.header().id, self.header().cancel_reason())
+    }
+
     fn cancel_task(self) {
         // Drop the future from a panic guard.
         let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
@@ -294,9 +299,9 @@ where
             // Dropping the future panicked, complete the join
             // handle with the panic to avoid dropping the panic
             // on the ground.
-            self.complete(Err(JoinError::panic(err)), true);
+            self.complete(Err(JoinError::panic(self.header().id, err)), true);
         } else {
-            self.complete(Err(JoinError::cancelled()), true);
+            self.complete(Err(self.cancelled()), true);
         }
     }
 
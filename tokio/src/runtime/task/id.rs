@@ -0,0 +1,27 @@
+use crate::loom::sync::atomic::{Ordering, StaticAtomicU64};
+
+use std::fmt;
+
+/// An opaque ID that uniquely identifies a task relative to all other tasks
+/// spawned in the process.
+///
+/// IDs are never reused, so an `Id` can be used to tell which of several
+/// tasks a [`JoinError`] was returned for.
+///
+/// [`JoinError`]: crate::task::JoinError
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Id(u64);
+
+impl Id {
+    pub(crate) fn next() -> Id {
+        static NEXT_ID: StaticAtomicU64 = StaticAtomicU64::new(1);
+
+        Id(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(fmt)
+    }
+}
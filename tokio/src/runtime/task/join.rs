@@ -1,4 +1,4 @@
-use crate::runtime::task::RawTask;
+use crate::runtime::task::{CancelReason, Id, RawTask};
 
 use std::fmt;
 use std::future::Future;
@@ -158,11 +158,28 @@ impl<T> JoinHandle<T> {
         }
     }
 
+    /// Returns the [`Id`] of the task associated with the handle.
+    ///
+    /// This is the same ID as the one returned by [`JoinError::id`] if the
+    /// task fails.
+    ///
+    /// [`Id`]: crate::task::Id
+    /// [`JoinError::id`]: crate::task::JoinError::id
+    pub fn id(&self) -> Id {
+        // The raw task is only taken when the handle is dropped.
+        self.raw.as_ref().expect("`JoinHandle` has no task").header().id
+    }
+
     /// Abort the task associated with the handle.
     ///
     /// Awaiting a cancelled task might complete as usual if the task was
     /// already completed at the time it was cancelled, but most likely it
-    /// will complete with a `Err(JoinError::Cancelled)`.
+    /// will complete with a `Err(JoinError::Cancelled)`, whose
+    /// [`cancel_reason`] is [`CancelReason::Aborted`] unless the runtime was
+    /// already shutting the task down.
+    ///
+    /// [`cancel_reason`]: crate::task::JoinError::cancel_reason
+    /// [`CancelReason::Aborted`]: crate::task::CancelReason::Aborted
     ///
     /// ```rust
     /// use tokio::time;
@@ -192,7 +209,7 @@ impl<T> JoinHandle<T> {
     /// ```
     pub fn abort(&self) {
         if let Some(raw) = self.raw {
-            raw.shutdown();
+            raw.shutdown(CancelReason::Aborted);
         }
     }
 }
@@ -4,11 +4,15 @@ pub(crate) use self::core::Header;
 
 mod error;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
-pub use self::error::JoinError;
+pub use self::error::{CancelReason, JoinError, TaskOutcome};
 
 mod harness;
 use self::harness::Harness;
 
+mod id;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::id::Id;
+
 mod join;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::join::JoinHandle;
@@ -161,7 +165,7 @@ cfg_rt_multi_thread! {
 impl<S: Schedule> Task<S> {
     /// Pre-emptively cancel the task as part of the shutdown process.
     pub(crate) fn shutdown(&self) {
-        self.raw.shutdown();
+        self.raw.shutdown(CancelReason::RuntimeShutdown);
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::runtime::task::{Cell, Harness, Header, Schedule, State};
+use crate::runtime::task::{CancelReason, Cell, Harness, Header, Schedule, State};
 
 use std::future::Future;
 use std::ptr::NonNull;
@@ -85,7 +85,11 @@ impl RawTask {
         unsafe { (vtable.drop_join_handle_slow)(self.ptr) }
     }
 
-    pub(super) fn shutdown(self) {
+    /// Cancels the task, recording `reason` unless it is already being
+    /// cancelled for another one.
+    pub(super) fn shutdown(self, reason: CancelReason) {
+        self.header().set_cancel_reason(reason);
+
         let vtable = self.header().vtable;
         unsafe { (vtable.shutdown)(self.ptr) }
     }
@@ -13,7 +13,7 @@ pub(crate) use worker::block_in_place;
 
 use crate::loom::sync::Arc;
 use crate::runtime::task::{self, JoinHandle};
-use crate::runtime::Parker;
+use crate::runtime::{Parker, Quiesce};
 use crate::time::Clock;
 
 use std::fmt;
@@ -44,8 +44,13 @@ pub(crate) struct Spawner {
 // ===== impl ThreadPool =====
 
 impl ThreadPool {
-    pub(crate) fn new(size: usize, parker: Parker, clock: Clock) -> (ThreadPool, Launch) {
-        let (shared, launch) = worker::create(size, parker, clock);
+    pub(crate) fn new(
+        size: usize,
+        parker: Parker,
+        clock: Clock,
+        quiesce: Quiesce,
+    ) -> (ThreadPool, Launch) {
+        let (shared, launch) = worker::create(size, parker, clock, quiesce);
         let spawner = Spawner { shared };
         let thread_pool = ThreadPool { spawner };
 
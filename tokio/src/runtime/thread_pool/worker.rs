@@ -12,7 +12,7 @@ use crate::runtime;
 use crate::runtime::enter::EnterContext;
 use crate::runtime::park::{Parker, Unparker};
 use crate::runtime::thread_pool::{AtomicCell, Idle};
-use crate::runtime::{queue, task};
+use crate::runtime::{queue, task, Quiesce};
 use crate::util::linked_list::{Link, LinkedList};
 use crate::util::FastRand;
 use crate::time::Clock;
@@ -86,6 +86,9 @@ pub(super) struct Shared {
     /// The core is **not** placed back in the worker to avoid it from being
     /// stolen by a thread that was spawned as part of `block_in_place`.
     shutdown_workers: Mutex<Vec<(Box<Core>, Arc<Worker>)>>,
+
+    /// Tracks whether all workers are parked while the clock is paused
+    quiesce: Quiesce,
 }
 
 /// Used to communicate with a worker from other threads.
@@ -129,7 +132,12 @@ type Notified = task::Notified<Arc<Worker>>;
 // Tracks thread-local state
 scoped_thread_local!(static CURRENT: Context);
 
-pub(super) fn create(size: usize, park: Parker, clock: Clock) -> (Arc<Shared>, Launch) {
+pub(super) fn create(
+    size: usize,
+    park: Parker,
+    clock: Clock,
+    quiesce: Quiesce,
+) -> (Arc<Shared>, Launch) {
     let mut cores = vec![];
     let mut remotes = vec![];
 
@@ -163,6 +171,7 @@ pub(super) fn create(size: usize, park: Parker, clock: Clock) -> (Arc<Shared>, L
         inject: queue::Inject::new(),
         idle: Idle::new(size),
         shutdown_workers: Mutex::new(vec![]),
+        quiesce,
     });
 
     let mut launch = Launch(vec![]);
@@ -296,6 +305,10 @@ fn run(worker: Arc<Worker>) {
 
     let clock = worker.clock.clone();
 
+    // Count this thread as driving the scheduler until it stops running the
+    // worker.
+    let _active = worker.shared.quiesce.enter();
+
     // Set the worker context.
     let cx = Context {
         worker,
@@ -399,6 +412,7 @@ impl Context {
 
     fn park(&self, mut core: Box<Core>) -> Box<Core> {
         core.transition_to_parked(&self.worker);
+        self.worker.shared.quiesce.before_park();
 
         while !core.is_shutdown {
             core = self.park_timeout(core, None);
@@ -407,10 +421,11 @@ impl Context {
             core.maintenance(&self.worker);
 
             if core.transition_from_parked(&self.worker) {
-                return core;
+                break;
             }
         }
 
+        self.worker.shared.quiesce.after_unpark();
         core
     }
 
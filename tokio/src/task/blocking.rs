@@ -83,11 +83,18 @@ cfg_rt_multi_thread! {
 /// still spawn additional threads for blocking operations. The basic
 /// scheduler's single thread is only used for asynchronous code.
 ///
+/// The blocking thread runs inside the runtime's context, so time queries such
+/// as [`Instant::now`] made by `f` read the runtime's clock and observe its
+/// pauses. A clock installed with [`with_clock`] by the spawning task is not
+/// carried over to `f`.
+///
 /// [`Builder`]: struct@crate::runtime::Builder
 /// [blocking]: ../index.html#cpu-bound-tasks-and-blocking-code
 /// [rayon]: https://docs.rs/rayon
 /// [`thread::spawn`]: fn@std::thread::spawn
 /// [`shutdown_timeout`]: fn@crate::runtime::Runtime::shutdown_timeout
+/// [`Instant::now`]: crate::time::Instant::now
+/// [`with_clock`]: crate::time::with_clock
 ///
 /// # Examples
 ///
@@ -216,7 +216,7 @@
 //! [`thread::yield_now`]: std::thread::yield_now
 
 cfg_rt! {
-    pub use crate::runtime::task::{JoinError, JoinHandle};
+    pub use crate::runtime::task::{CancelReason, Id, JoinError, JoinHandle, TaskOutcome};
 
     mod blocking;
     pub use blocking::spawn_blocking;
@@ -2,105 +2,2539 @@
 
 //! Source of time abstraction.
 //!
-//! By default, `std::time::Instant::now()` is used. However, when the
-//! `test-util` feature flag is enabled, the values returned for `now()` are
-//! configurable.
+//! By default, `std::time::Instant::now()` is used. Runtimes built with a
+//! pausable clock report time that stands still while the clock is paused,
+//! and when the `test-util` feature flag is enabled, the values returned for
+//! `now()` are configurable.
+
+use crate::park::Unpark;
+use crate::time::error::{ClockError, PanicPolicy};
+use crate::time::{PauseEvent, SleepRealtime};
+use crate::util::Callback;
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
+
+/// Returns the clock observed by the current task: the one installed by
+/// `with_clock` if any, and the runtime's clock otherwise.
+pub(super) fn clock() -> Option<Clock> {
+    with_clock(|clock| clock.cloned())
+}
+
+cfg_rt! {
+    fn with_clock<F, R>(f: F) -> R
+    where
+        F: FnOnce(Option<&Clock>) -> R,
+    {
+        crate::time::local_clock::with_override(|local| match local {
+            Some(clock) => f(Some(clock)),
+            None => crate::runtime::context::with_clock(f),
+        })
+    }
+}
+
+cfg_not_rt! {
+    fn with_clock<F, R>(f: F) -> R
+    where
+        F: FnOnce(Option<&Clock>) -> R,
+    {
+        crate::time::local_clock::with_override(f)
+    }
+}
+
+/// Wait until the runtime's clock is resumed.
+///
+/// Completes immediately if the clock is not paused. Unlike
+/// [`Runtime::wait_for_resume`], this does not block the current thread, so it
+/// is safe to call from within a task.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`Runtime::wait_for_resume`]: crate::runtime::Runtime::wait_for_resume
+pub async fn wait_for_resume() {
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+    let mut waiter = clock.resume_waiter();
+
+    crate::future::poll_fn(|cx| clock.poll_resumed_with(&mut waiter, cx)).await
+}
+
+/// Wait until the runtime's clock is paused.
+///
+/// Completes immediately if the clock is already paused. Unlike
+/// [`Runtime::wait_for_pause`], this does not block the current thread, so it
+/// is safe to call from within a task and to use with `select!`.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`Runtime::wait_for_pause`]: crate::runtime::Runtime::wait_for_pause
+pub async fn wait_for_pause() {
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+    let mut waiter = clock.pause_waiter();
+
+    crate::future::poll_fn(|cx| clock.poll_paused_with(&mut waiter, cx)).await
+}
+
+/// Wait until the runtime's paused clock is advanced.
+///
+/// Completes the next time the clock is advanced, by any amount, whatever
+/// the deadlines of the timers. This lets a task react to a test stepping
+/// time without committing to a duration. Advancing by zero does not count,
+/// as it leaves time where it is.
+///
+/// Completes immediately if the clock is not paused, and as soon as it is
+/// resumed, as time then moves on its own.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time::{self, Duration};
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+///
+/// let task = rt.spawn(time::sleep_until_advance());
+/// rt.block_on(tokio::task::yield_now());
+///
+/// rt.handle().advance_time(Duration::from_millis(1));
+/// rt.block_on(task).unwrap();
+/// ```
+pub async fn sleep_until_advance() {
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+    let advances = clock.advance_count();
+    let mut advance = clock.advance_waiter();
+    let mut resume = clock.resume_waiter();
+
+    crate::future::poll_fn(|cx| clock.poll_advanced_with(advances, &mut advance, &mut resume, cx))
+        .await
+}
+
+/// Wait until the runtime's clock is resumed, or until `timeout` of real time
+/// has elapsed.
+///
+/// Returns `true` if the clock is running and `false` if the timeout elapsed
+/// first. The timeout is measured on the real, wall clock rather than on the
+/// runtime's clock, so it elapses even though the runtime's clock is frozen.
+/// This protects test harnesses from hanging forever when a resume is
+/// accidentally skipped. A timeout too large to be added to the current
+/// instant never elapses.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime, or if the timeout can
+/// elapse and the runtime's time driver is not enabled.
+pub async fn wait_for_resume_timeout(timeout: std::time::Duration) -> bool {
+    use std::future::Future;
+    use std::pin::Pin;
+
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+    let mut timer = std::time::Instant::now()
+        .checked_add(timeout)
+        .map(SleepRealtime::until);
+    let mut waiter = clock.resume_waiter();
+
+    crate::future::poll_fn(|cx| {
+        if clock.poll_resumed_with(&mut waiter, cx).is_ready() {
+            return Poll::Ready(true);
+        }
+
+        match &mut timer {
+            Some(timer) => Pin::new(timer).poll(cx).map(|()| false),
+            None => Poll::Pending,
+        }
+    })
+    .await
+}
+
+cfg_sync! {
+    /// Returns a [`Notify`] that is notified each time the runtime's clock is
+    /// resumed.
+    ///
+    /// Every task waiting on [`Notify::notified`] is woken by
+    /// [`notify_waiters`] when the clock resumes, which composes with
+    /// `select!` and other futures. Unlike [`wait_for_resume`], a
+    /// `notified()` future created while the clock is running waits for the
+    /// next resume rather than completing immediately. As with
+    /// `notify_waiters`, no permit is stored, so a task that is not yet
+    /// waiting when the clock resumes misses that resume.
+    ///
+    /// The same `Notify` is returned on every call. Runtimes that were not
+    /// built with a pausable clock are never resumed, so it is never
+    /// notified for them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from outside of the Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time;
+    ///
+    /// # async fn dox() {
+    /// let resumed = time::resume_notify();
+    ///
+    /// tokio::select! {
+    ///     _ = resumed.notified() => println!("time resumed"),
+    ///     _ = time::sleep(time::Duration::from_secs(1)) => println!("timed out"),
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Notify`]: crate::sync::Notify
+    /// [`Notify::notified`]: crate::sync::Notify::notified
+    /// [`notify_waiters`]: crate::sync::Notify::notify_waiters
+    pub fn resume_notify() -> std::sync::Arc<crate::sync::Notify> {
+        clock()
+            .expect("time cannot be observed from outside the Tokio runtime")
+            .resume_notify()
+    }
+}
+
+/// Returns the logical time elapsed since the runtime's clock was created.
+///
+/// This is a pause-aware stopwatch: time spent paused is not counted, time
+/// the clock was advanced by is, and any elapsed time the runtime was built
+/// with is included. Unlike [`Runtime::elapsed_millis`], the result is not
+/// truncated to milliseconds.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`Runtime::elapsed_millis`]: crate::runtime::Runtime::elapsed_millis
+pub fn elapsed() -> std::time::Duration {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .elapsed()
+}
+
+/// Returns the logical time elapsed since the runtime's clock was created, in
+/// nanoseconds.
+///
+/// This is [`elapsed`] as a plain counter, the high resolution counterpart of
+/// [`Runtime::elapsed_millis`] for measurements that need sub-millisecond
+/// logical time, such as benchmarks run inside a pausable runtime. The value
+/// is constant while the clock is paused, unless the clock is advanced, and
+/// never decreases: after a resume, it carries on from where the pause left
+/// it. Both the pausable and the `test-util` clocks support it.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time;
+///
+/// # async fn dox() {
+/// let start = time::logical_nanos();
+/// time::sleep(time::Duration::from_micros(100)).await;
+/// assert!(time::logical_nanos() - start >= 100_000);
+/// # }
+/// ```
+///
+/// [`elapsed`]: crate::time::elapsed()
+/// [`Runtime::elapsed_millis`]: crate::runtime::Runtime::elapsed_millis
+pub fn logical_nanos() -> u128 {
+    elapsed().as_nanos()
+}
+
+/// Returns the number of times the runtime's clock has been paused or
+/// resumed.
+///
+/// The generation increases by one on every transition of the clock. Polling
+/// [`is_paused`] can miss a quick pause and resume, but comparing the
+/// generation before and after a computation reliably detects that the clock
+/// transitioned in between. Nested pauses and resumes that do not change the
+/// state of the clock are not transitions.
+///
+/// Runtimes that were not built with a pausable clock never transition, so
+/// this always returns `0` for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time;
+///
+/// # async fn dox() {
+/// let generation = time::pause_generation();
+/// let value = expensive_time_dependent_computation();
+///
+/// if time::pause_generation() != generation {
+///     // Time was frozen at some point during the computation.
+/// }
+/// # }
+/// # fn expensive_time_dependent_computation() {}
+/// ```
+pub fn pause_generation() -> u64 {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .pause_generation()
+}
+
+/// Returns `true` if the runtime's clock is paused.
+///
+/// This is equivalent to `is_paused_ordered(Ordering::SeqCst)`. Runtimes that
+/// were not built with a pausable clock are never paused.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn is_paused() -> bool {
+    is_paused_ordered(std::sync::atomic::Ordering::SeqCst)
+}
+
+/// Returns `true` if the runtime's clock is paused, loading the state with
+/// the given memory ordering.
+///
+/// With `SeqCst`, the result is consistent with every other sequentially
+/// consistent operation on the clock, which is what [`is_paused`] provides.
+/// `Acquire` additionally guarantees that everything done before the
+/// transition that was observed is visible. `Relaxed` avoids the fences
+/// altogether but only guarantees that the state is eventually observed,
+/// which is enough for a polling loop that checks the state repeatedly and
+/// does not rely on it to synchronize with other memory.
+///
+/// # Panics
+///
+/// Panics if `ordering` is `Release` or `AcqRel`, as for
+/// [`AtomicBool::load`], or if called from outside of the Tokio runtime.
+///
+/// [`AtomicBool::load`]: std::sync::atomic::AtomicBool::load
+pub fn is_paused_ordered(ordering: std::sync::atomic::Ordering) -> bool {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .is_paused_ordered(ordering)
+}
+
+/// Returns `true` if the runtime's clock is paused, reading the state with a
+/// single relaxed atomic load.
+///
+/// This is meant for very hot paths that check the state repeatedly, such as
+/// a guard evaluated on every iteration of a loop. It neither takes a lock
+/// nor synchronizes with the transition it observes, so the result may lag a
+/// concurrent pause or resume briefly. It is always the state of the clock
+/// before or after a transition, never a torn value, and a thread observes
+/// its own pauses and resumes immediately. Use [`is_paused`] when the result
+/// must be ordered with other operations on the clock.
+///
+/// Runtimes that were not built with a pausable clock are never paused.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`is_paused`]: crate::time::is_paused
+pub fn is_paused_relaxed() -> bool {
+    with_clock(|clock| {
+        clock
+            .expect("time cannot be observed from outside the Tokio runtime")
+            .is_paused_relaxed()
+    })
+}
+
+/// Returns the instant at which the runtime's clock is frozen, or `None` if
+/// the clock is running.
+///
+/// While the clock is paused, every call to [`Instant::now`] returns this
+/// same instant. Unlike `Instant::now`, this states the intent to observe the
+/// frozen instant, and it reads the instant and the paused state together,
+/// so the result cannot come from just before a concurrent pause or resume.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime::Builder;
+/// use tokio::time::{self, Instant};
+///
+/// let rt = Builder::new_current_thread()
+///     .enable_time()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     let frozen = time::frozen_now().unwrap();
+///     assert_eq!(Instant::now(), frozen);
+///     assert_eq!(Instant::now(), frozen);
+/// });
+/// ```
+///
+/// [`Instant::now`]: crate::time::Instant::now
+pub fn frozen_now() -> Option<crate::time::Instant> {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .frozen_now()
+}
+
+/// Returns the current instant of the runtime's clock as a
+/// `std::time::Instant`.
+///
+/// This is the same instant as [`Instant::now`], without the tokio wrapper,
+/// for interoperating with libraries that expect a `std::time::Instant`. It
+/// stands still while the clock is paused. Runtimes that were not built with
+/// a pausable clock, and calls made from outside of a runtime, get
+/// `std::time::Instant::now()`.
+///
+/// Instants of a pausable clock lag behind the system clock by the time the
+/// clock has spent paused, so they should only be compared with other
+/// instants of the same clock.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time;
+///
+/// # async fn dox() {
+/// let start = time::now_std();
+/// time::sleep(time::Duration::from_millis(10)).await;
+/// assert!(time::now_std() - start >= time::Duration::from_millis(10));
+/// # }
+/// ```
+///
+/// [`Instant::now`]: crate::time::Instant::now
+pub fn now_std() -> std::time::Instant {
+    now().into_std()
+}
+
+/// Returns how long the runtime's clock has left to run until `deadline`, or
+/// a zero duration if the deadline has passed.
+///
+/// This is `deadline - Instant::now()` without the risk of panicking on
+/// underflow, measured on the runtime's clock: while the clock is paused,
+/// the duration until a deadline stays the same, and advancing the clock
+/// shortens it. Calls made from outside of a runtime measure against the
+/// system clock.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, Duration, Instant};
+///
+/// # async fn dox() {
+/// let deadline = Instant::now() + Duration::from_secs(1);
+/// assert!(time::duration_until(deadline) <= Duration::from_secs(1));
+///
+/// let passed = Instant::now();
+/// assert_eq!(Duration::from_secs(0), time::duration_until(passed));
+/// # }
+/// ```
+pub fn duration_until(deadline: crate::time::Instant) -> std::time::Duration {
+    deadline.saturating_duration_since(now())
+}
+
+/// Returns the current wall-clock time according to the runtime's clock.
+///
+/// This is `SystemTime::now()` with the same offset applied as to
+/// [`Instant::now`]: it stands still while the clock is paused and moves
+/// forward when paused time is advanced, so that wall-clock timestamps taken
+/// through it are as deterministic as the runtime's instants. Precisely, it
+/// is the system time at which the runtime's clock was created, plus the
+/// logical time elapsed on that clock since.
+///
+/// Only reads made through this function are affected: the global
+/// `SystemTime::now()` keeps reporting the time of the operating system.
+/// Runtimes that were not built with a pausable clock, and calls made from
+/// outside of a runtime, get `SystemTime::now()`, give or take adjustments
+/// of the system clock since the runtime was created.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime::Builder;
+/// use tokio::time::{self, Duration};
+///
+/// let rt = Builder::new_current_thread()
+///     .enable_time()
+///     .pausable_time(true, Duration::from_secs(0))
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     let stamp = time::system_now();
+///     std::thread::sleep(Duration::from_millis(10));
+///     assert_eq!(stamp, time::system_now());
+/// });
+/// ```
+///
+/// [`Instant::now`]: crate::time::Instant::now
+pub fn system_now() -> std::time::SystemTime {
+    match clock() {
+        Some(clock) => clock.system_now(),
+        None => std::time::SystemTime::now(),
+    }
+}
+
+/// Pause the runtime's clock, failing instead of panicking or nesting.
+///
+/// Unlike [`Handle::pause_time`], this does not nest: if the clock is already
+/// paused it is left untouched and an error is returned, so a successful call
+/// is always balanced by exactly one [`try_resume`]. This makes it possible to
+/// write code that works on both pausable and ordinary runtimes without
+/// catching panics.
+///
+/// Returns `Ok(true)` once this call has paused the clock.
+///
+/// # Errors
+///
+/// Returns [`ClockError::NotPausable`] if the runtime was not built with a
+/// pausable clock and [`ClockError::AlreadyPaused`] if the clock is already
+/// paused.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`Handle::pause_time`]: crate::runtime::Handle::pause_time
+pub fn try_pause() -> Result<bool, ClockError> {
+    clock()
+        .expect("time cannot be frozen from outside the Tokio runtime")
+        .try_pause()
+}
+
+/// Resume the runtime's clock, failing instead of panicking.
+///
+/// Balances one pause of the clock. Returns `Ok(true)` if this call resumed
+/// the clock and `Ok(false)` if it was not paused or is still held paused by
+/// a nested pause.
+///
+/// # Errors
+///
+/// Returns [`ClockError::NotPausable`] if the runtime was not built with a
+/// pausable clock.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn try_resume() -> Result<bool, ClockError> {
+    clock()
+        .expect("time cannot be frozen from outside the Tokio runtime")
+        .try_resume()
+}
+
+/// Returns the total real time the runtime's clock has spent paused.
+///
+/// This includes the current pause if the clock is paused, and is useful to
+/// exclude time spent frozen from wall-clock measurements. Runtimes that were
+/// not built with a pausable clock are never paused, so this returns a zero
+/// duration for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn paused_duration() -> std::time::Duration {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .paused_duration()
+}
+
+/// Returns the real instant at which the current pause of the runtime's clock
+/// began, or `None` if the clock is not paused.
+///
+/// Nested pauses do not move it: it is the instant at which the clock stopped
+/// running. Together with `std::time::Instant::now()`, it tells how long the
+/// clock has been frozen while the pause is still going on, where
+/// [`paused_duration`] adds up every pause. Runtimes that were not built with
+/// a pausable clock are never paused, so this returns `None` for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time;
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     let since = time::last_pause_real_instant().unwrap();
+///     println!("frozen for {:?}", since.elapsed());
+/// });
+/// ```
+pub fn last_pause_real_instant() -> Option<std::time::Instant> {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .paused_since()
+}
+
+/// Advances the runtime's paused clock by `duration`.
+///
+/// This is the fallible counterpart of the `test-util` [`advance`], and works
+/// on both the pausable and the `test-util` clocks: the frozen instant
+/// returned by [`Instant::now`] moves forward by `duration`, the clock stays
+/// paused, and the current task yields once so that timers whose deadline
+/// was reached get a chance to fire.
+///
+/// # Errors
+///
+/// Returns [`ClockError::NotPaused`] without advancing the clock if it is
+/// not paused, [`ClockError::NotPausable`] if the runtime was not built with
+/// a pausable clock, and [`ClockError::Overflow`] without advancing the clock
+/// if advancing it by `duration` would overflow it.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, error::ClockError, Duration};
+///
+/// # async fn dox() {
+/// match time::try_advance(Duration::from_secs(1)).await {
+///     Ok(()) => {}
+///     Err(ClockError::NotPaused) => time::sleep(Duration::from_secs(1)).await,
+///     Err(e) => panic!("{}", e),
+/// }
+/// # }
+/// ```
+///
+/// [`advance`]: fn@crate::time::advance
+/// [`Instant::now`]: crate::time::Instant::now
+/// [`ClockError::NotPaused`]: crate::time::error::ClockError::NotPaused
+/// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+/// [`ClockError::Overflow`]: crate::time::error::ClockError::Overflow
+pub async fn try_advance(duration: std::time::Duration) -> Result<(), ClockError> {
+    clock()
+        .expect("time cannot be frozen from outside the Tokio runtime")
+        .try_advance(duration)?;
+
+    yield_once().await;
+    Ok(())
+}
+
+/// Yields once to the runtime, letting the time driver fire the timers an
+/// advance of the clock made due.
+async fn yield_once() {
+    let mut yielded = false;
+
+    crate::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Runs `action` while preventing the runtime's clock from being paused.
+///
+/// If the clock is currently paused, this blocks the current thread until it
+/// is resumed. Once `action` starts, any attempt to pause the clock waits for
+/// it to return, so `action` observes time moving without a pause in the
+/// middle of it. This is useful for a short critical section that computes a
+/// deadline and arms a timer.
+///
+/// Runtimes that were not built with a pausable clock simply run `action`.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn run_unpausable<T, F>(action: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .run_unpausable(action)
+}
+
+/// Runs `action` while preventing the runtime's clock from being resumed.
+///
+/// If the clock is running, this blocks the current thread until it is
+/// paused, so it should only be called from a task when the clock is known
+/// to be paused, for example right after pausing it. Once `action` starts,
+/// any attempt to resume the clock waits for it to return. This is the
+/// counterpart of [`run_unpausable`], and is useful to read several deadlines
+/// or instants that must all be taken at the same frozen time.
+///
+/// While `action` runs, `action` may rely on the following:
+///
+/// * the clock is paused, and stays paused until `action` returns;
+/// * [`Instant::now`] returns the same instant on every call, unless the
+///   clock is explicitly advanced;
+/// * no timer of the runtime fires, since timers only fire as the clock
+///   moves forward.
+///
+/// `action` must not pause or resume the clock itself, as resuming would wait
+/// for `action` to return and never complete.
+///
+/// # Errors
+///
+/// Returns [`ClockError::NotPausable`] without running `action` if the
+/// runtime was not built with a pausable clock, since such a clock is never
+/// paused and this would block forever. Returns [`ClockError::Unsupported`]
+/// without running `action` on the `test-util` clock, which cannot hold off a
+/// resume.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// [`run_unpausable`]: crate::time::run_unpausable
+/// [`Instant::now`]: crate::time::Instant::now
+/// [`ClockError::NotPausable`]: crate::time::error::ClockError::NotPausable
+/// [`ClockError::Unsupported`]: crate::time::error::ClockError::Unsupported
+pub fn run_unresumable<T, F>(action: F) -> Result<T, ClockError>
+where
+    F: FnOnce() -> T,
+{
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .run_unresumable(action)
+}
+
+/// Runs `future` with the runtime's clock paused.
+///
+/// The clock is paused when `future` is first polled and resumed once it
+/// completes, as if time stood still while it ran. The pause is held by a
+/// [`PauseGuard`], so the clock is also resumed if `future` panics or if the
+/// returned future is dropped before completing. Pauses nest, so a clock that
+/// was already paused stays paused afterwards.
+///
+/// Runtimes that were not built with a pausable clock cannot stand still, so
+/// `future` simply runs with time moving as usual. Use [`is_paused`] to tell
+/// the two cases apart.
+///
+/// Timers do not fire while the clock is paused: awaiting a [`sleep`] inside
+/// `future` waits until something advances the clock.
+///
+/// # Panics
+///
+/// Panics if polled from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time::{self, Duration, Instant};
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .pausable_time(false, Duration::from_secs(0))
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     time::frozen(async {
+///         let start = Instant::now();
+///         std::thread::sleep(std::time::Duration::from_millis(10));
+///         assert_eq!(start, Instant::now());
+///     })
+///     .await;
+///
+///     assert!(!time::is_paused());
+/// });
+/// ```
+///
+/// [`PauseGuard`]: crate::time::PauseGuard
+/// [`is_paused`]: crate::time::is_paused
+/// [`sleep`]: crate::time::sleep
+pub async fn frozen<F: std::future::Future>(future: F) -> F::Output {
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+
+    let _guard = if clock.pausable() {
+        Some(crate::time::PauseGuard::new(clock))
+    } else {
+        None
+    };
+
+    future.await
+}
+
+/// Runs `action` if the runtime's clock is paused, keeping it paused until
+/// `action` returns.
+///
+/// Checking the state and acting on it happen atomically with respect to
+/// transitions of the clock, avoiding the race of calling `is_paused` and then
+/// acting on a state that may already have changed. Returns `None` without
+/// running `action` if the clock was resumed at the moment of the call.
+///
+/// Runtimes that were not built with a pausable clock are never paused, so
+/// this always returns `None` for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn run_if_paused<T, F>(action: F) -> Option<T>
+where
+    F: FnOnce() -> T,
+{
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .run_if_paused(action)
+}
+
+/// Runs `action` if the runtime's clock is resumed, preventing it from being
+/// paused until `action` returns.
+///
+/// Checking the state and acting on it happen atomically with respect to
+/// transitions of the clock. Returns `None` without running `action` if the
+/// clock was paused at the moment of the call.
+///
+/// Runtimes that were not built with a pausable clock are never paused, so
+/// this always runs `action` for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn run_if_resumed<T, F>(action: F) -> Option<T>
+where
+    F: FnOnce() -> T,
+{
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .run_if_resumed(action)
+}
+
+/// Captures the state of the runtime's clock at a single instant.
+///
+/// The current time, whether the clock is paused, and the elapsed time are
+/// read while the clock is held in its current state, so the snapshot cannot
+/// tear across a pause or resume. Reading them separately can, for example,
+/// report the clock as paused together with a time sampled before the pause.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn clock_snapshot() -> ClockSnapshot {
+    let clock = clock().expect("time cannot be observed from outside the Tokio runtime");
+
+    clock.read_held(|is_paused| clock.snapshot(is_paused))
+}
+
+/// Asserts that the runtime's clock is paused.
+///
+/// This is meant for tests of code running on a pausable clock. Compared to
+/// `assert!(time::is_paused())`, the failure message tells which state was
+/// expected and reports the clock's current instant and elapsed time.
+///
+/// # Panics
+///
+/// Panics if the clock is running, including on runtimes that were not built
+/// with a pausable clock, or if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time;
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     time::assert_paused();
+///
+///     time::try_resume().unwrap();
+///     time::assert_resumed();
+/// });
+/// ```
+#[cfg_attr(tokio_track_caller, track_caller)]
+pub fn assert_paused() {
+    let snapshot = clock_snapshot();
+
+    if !snapshot.is_paused() {
+        panic!(
+            "expected the runtime's clock to be paused, but it is running at {:?} ({} ms elapsed)",
+            snapshot.now(),
+            snapshot.elapsed_millis()
+        );
+    }
+}
+
+/// Asserts that the runtime's clock is running.
+///
+/// This is the counterpart of [`assert_paused`].
+///
+/// # Panics
+///
+/// Panics if the clock is paused, or if called from outside of the Tokio
+/// runtime.
+#[cfg_attr(tokio_track_caller, track_caller)]
+pub fn assert_resumed() {
+    let snapshot = clock_snapshot();
+
+    if snapshot.is_paused() {
+        panic!(
+            "expected the runtime's clock to be running, but it is paused at {:?} ({} ms elapsed)",
+            snapshot.now(),
+            snapshot.elapsed_millis()
+        );
+    }
+}
+
+/// State of the runtime's clock at a single instant, returned by
+/// [`clock_snapshot`] and [`Handle::pause_and_snapshot`].
+///
+/// [`Handle::pause_and_snapshot`]: crate::runtime::Handle::pause_and_snapshot
+#[derive(Debug, Clone)]
+pub struct ClockSnapshot {
+    now: crate::time::Instant,
+    is_paused: bool,
+    elapsed_millis: u64,
+}
+
+impl ClockSnapshot {
+    /// Returns the clock's current instant at the time of the snapshot.
+    pub fn now(&self) -> crate::time::Instant {
+        self.now
+    }
+
+    /// Returns `true` if the clock was paused at the time of the snapshot.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Returns the milliseconds of logical time elapsed since the clock was
+    /// created, including any elapsed time it was built with.
+    ///
+    /// Clocks that are not pausable count real time since the runtime was
+    /// built.
+    pub fn elapsed_millis(&self) -> u64 {
+        self.elapsed_millis
+    }
+}
+
+/// Captures the logical state of the runtime's clock so that it can be
+/// restored later, possibly in another process, with
+/// [`Builder::restore_clock`].
+///
+/// The state is read consistently with respect to concurrent pauses and
+/// resumes. See [`ClockState`] for what restoring it preserves.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime::Builder;
+/// use tokio::time;
+///
+/// let rt = Builder::new_current_thread()
+///     .enable_time()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+///
+/// let state = rt.block_on(async { time::clock_state() });
+/// assert!(state.is_paused());
+///
+/// let restored = Builder::new_current_thread()
+///     .enable_time()
+///     .restore_clock(state)
+///     .build();
+///
+/// // With the `test-util` feature, `build` rejects the elapsed time that
+/// // the state carries.
+/// if let Ok(restored) = restored {
+///     assert!(restored.handle().is_time_paused());
+/// }
+/// ```
+///
+/// [`Builder::restore_clock`]: crate::runtime::Builder::restore_clock
+pub fn clock_state() -> ClockState {
+    clock()
+        .expect("time cannot be observed from outside the Tokio runtime")
+        .to_state()
+}
+
+/// The logical state of a clock, returned by [`clock_state`] and consumed by
+/// [`Builder::restore_clock`].
+///
+/// `Instant`s are only meaningful within the process that created them, so
+/// the state records the clock's position as durations: the logical time
+/// elapsed on the clock, whether it was paused, and the real time it has
+/// spent paused. The fields are plain values so the state can be persisted
+/// in any format and rebuilt with [`ClockState::new`].
+///
+/// A clock restored from a state reports the same logical time as the
+/// original did when the state was captured: [`elapsed`] starts out at
+/// [`ClockState::elapsed`], and [`paused_duration`] at
+/// [`ClockState::paused_duration`]. Real time that passed between capturing
+/// and restoring the state is not counted, so logical time picks up exactly
+/// where it left off. A restored clock that was paused stays paused until it
+/// is resumed.
+///
+/// [`Builder::restore_clock`]: crate::runtime::Builder::restore_clock
+/// [`elapsed`]: crate::time::elapsed
+/// [`paused_duration`]: crate::time::paused_duration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockState {
+    elapsed: std::time::Duration,
+    is_paused: bool,
+    paused_duration: std::time::Duration,
+}
+
+impl ClockState {
+    /// Creates a clock state from its parts, for example after reading them
+    /// back from storage.
+    pub fn new(
+        elapsed: std::time::Duration,
+        is_paused: bool,
+        paused_duration: std::time::Duration,
+    ) -> ClockState {
+        ClockState {
+            elapsed,
+            is_paused,
+            paused_duration,
+        }
+    }
+
+    /// Returns the logical time elapsed on the clock, including any elapsed
+    /// time it was built with.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.elapsed
+    }
+
+    /// Returns `true` if the clock was paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Returns the real time the clock has spent paused.
+    pub fn paused_duration(&self) -> std::time::Duration {
+        self.paused_duration
+    }
+}
+
+/// The source of time used by a runtime's clock, returned by
+/// [`Handle::clock_kind`].
+///
+/// [`Handle::clock_kind`]: crate::runtime::Handle::clock_kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockKind {
+    /// The clock follows real time and cannot be paused.
+    System,
+
+    /// The clock follows real time but can be paused and resumed.
+    Pausable,
+
+    /// The clock is the test clock enabled by the `test-util` feature, which
+    /// can be paused and advanced manually.
+    Test,
+}
+
+/// Maximum number of transitions buffered for `PauseEvents` consumers.
+const PAUSE_EVENTS_CAPACITY: usize = 64;
+
+/// Largest time scale a runtime accepts. Scaled time is computed with
+/// `Duration` and `Instant` arithmetic that panics on overflow, which this
+/// keeps out of reach of any realistic run.
+pub(crate) const MAX_TIME_SCALE: f64 = 1_000_000.0;
+
+/// Callback run when a `Clock` is advanced, with its logical instant before
+/// and after the advance.
+pub(crate) type WarpCallback =
+    std::sync::Arc<dyn Fn(crate::time::Instant, crate::time::Instant) + Send + Sync>;
+
+thread_local! {
+    /// The clocks whose warp callback the current thread is running, keyed
+    /// by the address of their `Waiters`.
+    static IN_WARP: std::cell::RefCell<Vec<usize>> = std::cell::RefCell::new(Vec::new());
+}
+
+/// Marks a clock as running its warp callback on the current thread, until
+/// dropped.
+struct WarpGuard {
+    key: usize,
+}
+
+impl WarpGuard {
+    fn enter(key: usize) -> WarpGuard {
+        IN_WARP.with(|in_warp| in_warp.borrow_mut().push(key));
+        WarpGuard { key }
+    }
+}
+
+impl Drop for WarpGuard {
+    fn drop(&mut self) {
+        IN_WARP.with(|in_warp| {
+            let mut in_warp = in_warp.borrow_mut();
+
+            if let Some(i) = in_warp.iter().rposition(|&key| key == self.key) {
+                in_warp.remove(i);
+            }
+        });
+    }
+}
+
+/// Tasks and callbacks waiting on a state transition of a `Clock`, with the
+/// bookkeeping of those transitions.
+#[derive(Default)]
+struct Waiters {
+    /// Run each time the clock is paused, before waking tasks.
+    on_pause: Option<Callback>,
+
+    /// Run each time the clock is resumed, before waking tasks.
+    on_resume: Option<Callback>,
+
+    /// Run each time the clock is advanced.
+    on_warp: Option<WarpCallback>,
+
+    /// Whether fallible operations panic or fail on misuse.
+    panic_policy: PanicPolicy,
+
+    /// Whether to release every pause once the last time driver using the
+    /// clock shuts down. Set for the clocks runtimes create for themselves.
+    resume_on_shutdown: bool,
+
+    /// Tasks to notify the next time the clock is paused.
+    pause: Mutex<WaitList>,
+
+    /// Tasks to notify the next time the clock is resumed.
+    resume: Mutex<WaitList>,
+
+    /// Tasks to notify the next time the clock is advanced.
+    advance: Mutex<WaitList>,
+
+    /// Number of times the clock was advanced. Only compared for changes,
+    /// so it may wrap.
+    advances: std::sync::atomic::AtomicUsize,
+
+    /// Recent transitions, for `PauseEvents` consumers.
+    events: Mutex<Events>,
+
+    /// Time drivers driven by this clock. They are unparked whenever logical
+    /// time changes in a way the drivers cannot anticipate.
+    drivers: Mutex<Drivers>,
+
+    /// Real time spent paused.
+    paused_time: Mutex<PausedTime>,
+
+    /// Wall-clock time at which the clock was created, for `system_now`.
+    system_origin: SystemOrigin,
+
+    /// Notified of every resume, handed out by `resume_notify`.
+    #[cfg(feature = "sync")]
+    resume_notify: std::sync::Arc<crate::sync::Notify>,
+}
+
+/// The system time at the creation of a clock, with the instant at which it
+/// was read.
+#[derive(Debug)]
+struct SystemOrigin {
+    system: std::time::SystemTime,
+    instant: std::time::Instant,
+}
+
+impl Default for SystemOrigin {
+    fn default() -> SystemOrigin {
+        SystemOrigin {
+            system: std::time::SystemTime::now(),
+            instant: std::time::Instant::now(),
+        }
+    }
+}
+
+impl SystemOrigin {
+    /// Returns the system time at `now`, saturating at the bounds of
+    /// `SystemTime` rather than overflowing.
+    fn at(&self, now: std::time::Instant) -> std::time::SystemTime {
+        use std::time::SystemTime;
+
+        match now.checked_duration_since(self.instant) {
+            Some(elapsed) => saturate(self.system, elapsed, SystemTime::checked_add),
+            // Pausable clocks created paused, or with a time scale, may have
+            // lagged behind real time since the origin was read.
+            None => saturate(self.system, self.instant - now, SystemTime::checked_sub),
+        }
+    }
+}
+
+/// Moves `time` by `by` with `step`, or as far as `SystemTime` can represent
+/// if that overflows.
+fn saturate(
+    mut time: std::time::SystemTime,
+    mut by: std::time::Duration,
+    step: fn(&std::time::SystemTime, std::time::Duration) -> Option<std::time::SystemTime>,
+) -> std::time::SystemTime {
+    if let Some(moved) = step(&time, by) {
+        return moved;
+    }
+
+    // Moving by ever smaller amounts, each tried at most once after a larger
+    // one overflowed, ends within a nanosecond of the bound.
+    while by > std::time::Duration::from_secs(0) {
+        match step(&time, by) {
+            Some(moved) => time = moved,
+            None => by /= 2,
+        }
+    }
+
+    time
+}
+
+#[derive(Debug, Default)]
+struct PausedTime {
+    /// Real instant at which the current pause began, if paused.
+    since: Option<std::time::Instant>,
+
+    /// Real time spent in pauses that have ended.
+    total: std::time::Duration,
+
+    /// Number of times the clock was paused.
+    pauses: u64,
+
+    /// Number of times the clock was resumed.
+    resumes: u64,
+}
+
+/// Time drivers registered with a clock, keyed so that a driver can withdraw
+/// its registration when it shuts down.
+#[derive(Default)]
+struct Drivers {
+    unparkers: Vec<(u64, Box<dyn Unpark>)>,
+
+    /// Key handed out to the next driver that registers.
+    next_key: u64,
+}
+
+/// Tasks waiting for the next pause or resume of a clock.
+#[derive(Debug, Default)]
+struct WaitList {
+    /// Registered wakers, with the key of the waiter that registered each of
+    /// them. Waiters without a key cannot withdraw their registration, which
+    /// lasts until the next transition.
+    wakers: Vec<(Option<u64>, Waker)>,
+
+    /// Key handed out to the next waiter that asks for one.
+    next_key: u64,
+}
+
+impl WaitList {
+    /// Registers `waker`, replacing the waker previously registered under
+    /// `key`. Without a key, `waker` is only added if no equivalent waker is
+    /// registered already, so that repeatedly polling a waiter does not grow
+    /// the list.
+    fn register(&mut self, key: Option<u64>, waker: &Waker) {
+        let existing = self.wakers.iter_mut().find(|(k, w)| match key {
+            Some(_) => *k == key,
+            None => k.is_none() && w.will_wake(waker),
+        });
+
+        match existing {
+            Some((_, registered)) => {
+                if !registered.will_wake(waker) {
+                    *registered = waker.clone();
+                }
+            }
+            None => self.wakers.push((key, waker.clone())),
+        }
+    }
+
+    /// Hands out a key identifying a waiter's registration.
+    fn next_key(&mut self) -> u64 {
+        let key = self.next_key;
+        self.next_key += 1;
+        key
+    }
+
+    /// Withdraws the registration made under `key`, if any.
+    fn remove(&mut self, key: u64) {
+        self.wakers.retain(|(k, _)| *k != Some(key));
+    }
+}
+
+/// A task's registration to be woken by the next pause or resume of a clock.
+///
+/// Dropping it withdraws the registration, so that a wait abandoned before
+/// the transition, for instance by `select!`, leaves no waker behind.
+struct Waiter<'a> {
+    list: &'a Mutex<WaitList>,
+
+    /// Key of the registration, once registered.
+    key: Option<u64>,
+}
+
+impl Waiter<'_> {
+    fn register(&mut self, waker: &Waker) {
+        let mut list = self.list.lock().unwrap();
+        let key = *self.key.get_or_insert_with(|| list.next_key());
+
+        list.register(Some(key), waker);
+    }
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.list.lock().unwrap().remove(key);
+        }
+    }
+}
+
+impl PausedTime {
+    /// Returns the real time spent paused, including the current pause.
+    fn duration(&self) -> std::time::Duration {
+        match self.since {
+            Some(since) => self.total + since.elapsed(),
+            None => self.total,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Events {
+    /// The most recent transitions, oldest first.
+    buffer: VecDeque<PauseEvent>,
+
+    /// Sequence number that the next transition will be assigned.
+    next: u64,
+
+    /// Consumers waiting for the next transition, registered under the key
+    /// of their stream.
+    wakers: WaitList,
+}
+
+impl std::fmt::Debug for Waiters {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Waiters")
+            .field("on_pause", &self.on_pause.as_ref().map(|_| "..."))
+            .field("on_resume", &self.on_resume.as_ref().map(|_| "..."))
+            .field("on_warp", &self.on_warp.as_ref().map(|_| "..."))
+            .field("panic_policy", &self.panic_policy)
+            .field("resume_on_shutdown", &self.resume_on_shutdown)
+            .field("pause", &self.pause)
+            .field("resume", &self.resume)
+            .field("advance", &self.advance)
+            .field("advances", &self.advances)
+            .field("events", &self.events)
+            .field("drivers", &self.drivers.lock().unwrap().unparkers.len())
+            .field("paused_time", &self.paused_time)
+            .field("system_origin", &self.system_origin)
+            .finish()
+    }
+}
+
+impl Waiters {
+    fn register_pause(&self, waker: &Waker) {
+        self.pause.lock().unwrap().register(None, waker);
+    }
+
+    fn register_resume(&self, waker: &Waker) {
+        self.resume.lock().unwrap().register(None, waker);
+    }
+
+    /// Records that the clock was just paused and wakes the tasks waiting for
+    /// it.
+    fn paused(&self) {
+        self.record_pause();
+        self.push_event(PauseEvent::Paused);
+        Self::wake_all(&self.pause);
+    }
+
+    /// Records that the clock was just resumed and wakes the tasks waiting
+    /// for it. Returns the real time spent in the pause that just ended.
+    fn resumed(&self) -> std::time::Duration {
+        let paused_for = self.record_resume();
+        self.push_event(PauseEvent::Resumed);
+        Self::wake_all(&self.resume);
+
+        #[cfg(feature = "sync")]
+        self.resume_notify.notify_waiters();
+
+        paused_for
+    }
+
+    fn record_pause(&self) {
+        let mut paused_time = self.paused_time.lock().unwrap();
+
+        paused_time.since = Some(std::time::Instant::now());
+        paused_time.pauses += 1;
+    }
+
+    fn record_resume(&self) -> std::time::Duration {
+        let mut paused_time = self.paused_time.lock().unwrap();
+
+        match paused_time.since.take() {
+            Some(since) => {
+                let paused_for = since.elapsed();
+                paused_time.total += paused_for;
+                paused_time.resumes += 1;
+                paused_for
+            }
+            None => std::time::Duration::default(),
+        }
+    }
+
+    fn paused_duration(&self) -> std::time::Duration {
+        self.paused_time.lock().unwrap().duration()
+    }
+
+    /// Runs the warp callback, if any, for an advance of logical time from
+    /// `old` to `new`. The caller must not hold any lock of the clock.
+    fn warped(&self, old: crate::time::Instant, new: crate::time::Instant) {
+        use std::sync::atomic::Ordering::SeqCst;
+
+        if old != new {
+            self.advances.fetch_add(1, SeqCst);
+            Self::wake_all(&self.advance);
+        }
+
+        if let Some(f) = &self.on_warp {
+            if old != new {
+                let _guard = WarpGuard::enter(self.warp_key());
+
+                f(old, new);
+            }
+        }
+    }
+
+    /// Identifies this clock among those running a warp callback.
+    fn warp_key(&self) -> usize {
+        self as *const Waiters as usize
+    }
+
+    /// Whether the current thread is running the warp callback of this
+    /// clock. Other clocks may be used freely from within the callback.
+    fn is_warping(&self) -> bool {
+        let key = self.warp_key();
+        IN_WARP.with(|in_warp| in_warp.borrow().contains(&key))
+    }
+
+    /// Panics if called from within the warp callback of this clock, which
+    /// must not change its state.
+    fn check_not_warping(&self) {
+        assert!(
+            !self.is_warping(),
+            "a clock cannot be paused, resumed or advanced from within an `on_time_warp` callback"
+        );
+    }
+
+    /// Like `check_not_warping`, failing instead of panicking under
+    /// `PanicPolicy::Error`. Used by the operations that return a
+    /// `ClockError`.
+    fn try_check_not_warping(&self) -> Result<(), ClockError> {
+        match self.panic_policy {
+            PanicPolicy::Panic => {
+                self.check_not_warping();
+                Ok(())
+            }
+            PanicPolicy::Error if self.is_warping() => Err(ClockError::InWarpCallback),
+            PanicPolicy::Error => Ok(()),
+        }
+    }
+
+    fn push_event(&self, event: PauseEvent) {
+        let wakers = {
+            let mut events = self.events.lock().unwrap();
+
+            if events.buffer.len() == PAUSE_EVENTS_CAPACITY {
+                events.buffer.pop_front();
+            }
+
+            events.buffer.push_back(event);
+            events.next += 1;
+
+            std::mem::take(&mut events.wakers.wakers)
+        };
+
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+
+    #[cfg_attr(feature = "test-util", allow(dead_code))]
+    fn unpark_drivers(&self) {
+        for (_, unpark) in self.drivers.lock().unwrap().unparkers.iter() {
+            unpark.unpark();
+        }
+    }
+
+    fn wake_all(list: &Mutex<WaitList>) {
+        let wakers = std::mem::take(&mut list.lock().unwrap().wakers);
+
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Clock {
+    /// Sets the callbacks to run on each transition of the clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock has already been cloned.
+    pub(crate) fn with_callbacks(
+        mut self,
+        on_pause: Option<Callback>,
+        on_resume: Option<Callback>,
+    ) -> Clock {
+        let waiters = std::sync::Arc::get_mut(&mut self.waiters)
+            .expect("callbacks must be set before the clock is shared");
+
+        waiters.on_pause = on_pause;
+        waiters.on_resume = on_resume;
+        self
+    }
+
+    /// Sets the callback to run each time the clock is advanced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock has already been cloned.
+    pub(crate) fn with_warp_callback(mut self, on_warp: Option<WarpCallback>) -> Clock {
+        let waiters = std::sync::Arc::get_mut(&mut self.waiters)
+            .expect("callbacks must be set before the clock is shared");
+
+        waiters.on_warp = on_warp;
+        self
+    }
+
+    /// Sets how the fallible operations of the clock report misuse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock has already been cloned.
+    pub(crate) fn with_panic_policy(mut self, panic_policy: PanicPolicy) -> Clock {
+        let waiters = std::sync::Arc::get_mut(&mut self.waiters)
+            .expect("the panic policy must be set before the clock is shared");
+
+        waiters.panic_policy = panic_policy;
+        self
+    }
+
+    /// Makes the clock release every pause once the last time driver using
+    /// it shuts down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock has already been cloned.
+    pub(crate) fn with_resume_on_shutdown(mut self) -> Clock {
+        let waiters = std::sync::Arc::get_mut(&mut self.waiters)
+            .expect("shutdown behavior must be set before the clock is shared");
+
+        waiters.resume_on_shutdown = true;
+        self
+    }
+
+    /// Fails with `ClockError::InWarpCallback` if called from within a warp
+    /// callback under `PanicPolicy::Error`, and panics there otherwise.
+    pub(crate) fn check_not_warping(&self) -> Result<(), ClockError> {
+        self.waiters.try_check_not_warping()
+    }
+
+    /// Sets the real time the clock reports having spent paused before it was
+    /// created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock has already been cloned.
+    pub(crate) fn with_paused_duration(mut self, paused_duration: std::time::Duration) -> Clock {
+        let waiters = std::sync::Arc::get_mut(&mut self.waiters)
+            .expect("paused duration must be set before the clock is shared");
+
+        waiters.paused_time.get_mut().unwrap().total = paused_duration;
+        self
+    }
+
+    /// Returns the source of time this clock uses.
+    pub(crate) fn kind(&self) -> ClockKind {
+        if Clock::is_test() {
+            ClockKind::Test
+        } else if self.pausable() {
+            ClockKind::Pausable
+        } else {
+            ClockKind::System
+        }
+    }
+
+    /// Returns `true` if both handles refer to the same underlying clock.
+    pub(crate) fn ptr_eq(&self, other: &Clock) -> bool {
+        std::sync::Arc::ptr_eq(&self.waiters, &other.waiters)
+    }
+
+    /// Returns the real time the clock has spent paused, including the
+    /// current pause if the clock is paused.
+    pub(crate) fn paused_duration(&self) -> std::time::Duration {
+        self.waiters.paused_duration()
+    }
+
+    /// Returns the system time at which the clock was created, moved by the
+    /// logical time elapsed since.
+    pub(crate) fn system_now(&self) -> std::time::SystemTime {
+        self.waiters.system_origin.at(self.now().into_std())
+    }
+
+    /// Emits a `tracing` event for a pause or resume of the clock, with its
+    /// logical instant and the total time it has spent paused.
+    #[cfg(feature = "tracing")]
+    fn trace_transition(&self, paused: bool) {
+        tracing::trace!(
+            target: "tokio::time::clock",
+            paused = paused,
+            now = ?self.now(),
+            paused_duration = ?self.paused_duration(),
+            "clock {}",
+            if paused { "paused" } else { "resumed" },
+        );
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[inline]
+    fn trace_transition(&self, _paused: bool) {}
+
+    /// Returns counters of the clock's pauses and resumes, read together.
+    #[cfg(feature = "rt")]
+    pub(crate) fn pause_metrics(&self) -> crate::runtime::PauseMetrics {
+        let paused_time = self.waiters.paused_time.lock().unwrap();
+
+        crate::runtime::PauseMetrics {
+            pause_count: paused_time.pauses,
+            resume_count: paused_time.resumes,
+            total_paused_duration: paused_time.duration(),
+        }
+    }
+
+    /// Reads the clock into a snapshot. The caller holds the clock in the
+    /// state given by `is_paused`.
+    fn snapshot(&self, is_paused: bool) -> ClockSnapshot {
+        ClockSnapshot {
+            now: self.now(),
+            is_paused,
+            elapsed_millis: self.elapsed_millis(),
+        }
+    }
+
+    /// Pauses the clock and snapshots it as soon as it is frozen, before it
+    /// can be resumed. Returns whether this call paused the clock, like
+    /// `pause`.
+    pub(crate) fn pause_and_snapshot(&self) -> (bool, ClockSnapshot) {
+        self.pause_with(|| self.snapshot(true))
+    }
+
+    /// Captures the logical state of the clock, without racing a concurrent
+    /// pause or resume.
+    pub(crate) fn to_state(&self) -> ClockState {
+        self.read_held(|is_paused| ClockState {
+            elapsed: self.elapsed(),
+            is_paused,
+            paused_duration: self.paused_duration(),
+        })
+    }
+
+    /// Runs `read` with whether the clock is paused, holding the clock in
+    /// that state until `read` returns.
+    fn read_held<T, F>(&self, read: F) -> T
+    where
+        F: Fn(bool) -> T,
+    {
+        loop {
+            if let Some(value) = self.run_if_paused(|| read(true)) {
+                return value;
+            }
+
+            if let Some(value) = self.run_if_resumed(|| read(false)) {
+                return value;
+            }
+        }
+    }
+
+    /// Registers a time driver to be unparked when logical time changes
+    /// underneath it, for example when a paused clock is advanced. Returns
+    /// the key to pass to `unregister_driver` when the driver shuts down.
+    pub(crate) fn register_driver(&self, unpark: Box<dyn Unpark>) -> u64 {
+        let mut drivers = self.waiters.drivers.lock().unwrap();
+        let key = drivers.next_key;
+
+        drivers.next_key += 1;
+        drivers.unparkers.push((key, unpark));
+        key
+    }
+
+    /// Withdraws the registration of a time driver that shut down. A clock
+    /// shared between runtimes outlives their drivers.
+    ///
+    /// Once the last driver of a clock created by its runtime is gone, the
+    /// clock is resumed. Otherwise, anything still waiting for a resume,
+    /// such as a blocking task that the runtime waits for as it shuts down,
+    /// would wait forever.
+    pub(crate) fn unregister_driver(&self, key: u64) {
+        let last = {
+            let mut drivers = self.waiters.drivers.lock().unwrap();
+            drivers.unparkers.retain(|(k, _)| *k != key);
+            drivers.unparkers.is_empty()
+        };
+
+        if last && self.waiters.resume_on_shutdown {
+            while self.pause_depth() > 0 {
+                self.resume();
+            }
+        }
+    }
+
+    /// Returns the `Notify` that is notified of every resume of the clock.
+    #[cfg(feature = "sync")]
+    pub(crate) fn resume_notify(&self) -> std::sync::Arc<crate::sync::Notify> {
+        self.waiters.resume_notify.clone()
+    }
+
+    /// Returns the number of times the clock has been paused or resumed,
+    /// which is also the sequence number of its next transition.
+    pub(crate) fn pause_generation(&self) -> u64 {
+        self.waiters.events.lock().unwrap().next
+    }
+
+    /// Resumes the clock if it has not transitioned since the transition
+    /// count was `generation`.
+    pub(crate) fn resume_if_unchanged(&self, generation: u64) -> bool {
+        self.read_held(|is_paused| is_paused && self.pause_generation() == generation)
+            && self.resume()
+    }
+
+    /// Polls for the transition with sequence number `next`, advancing `next`
+    /// past the returned event.
+    ///
+    /// While pending, the task is registered under `key`, which is assigned
+    /// on the first registration and must be passed to `remove_pause_waker`
+    /// once the consumer goes away.
+    pub(crate) fn poll_pause_event(
+        &self,
+        next: &mut u64,
+        key: &mut Option<u64>,
+        cx: &mut Context<'_>,
+    ) -> Poll<PauseEvent> {
+        let mut events = self.waiters.events.lock().unwrap();
+        let oldest = events.next - events.buffer.len() as u64;
+
+        if *next < oldest {
+            let lagged = oldest - *next;
+            *next = oldest;
+            return Poll::Ready(PauseEvent::Lagged(lagged));
+        }
+
+        if *next < events.next {
+            let event = events.buffer[(*next - oldest) as usize];
+            *next += 1;
+            return Poll::Ready(event);
+        }
+
+        let key = *key.get_or_insert_with(|| events.wakers.next_key());
+        events.wakers.register(Some(key), cx.waker());
+        Poll::Pending
+    }
+
+    /// Withdraws the registration a pause event consumer made under `key`.
+    pub(crate) fn remove_pause_waker(&self, key: u64) {
+        self.waiters.events.lock().unwrap().wakers.remove(key);
+    }
+
+    /// Polls for the clock to be paused, registering the task to be notified
+    /// on the next pause if it is currently resumed.
+    pub(crate) fn poll_paused(&self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        self.waiters.register_pause(cx.waker());
+
+        // The clock may have been paused before the waker was registered.
+        if self.is_paused() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Returns a registration to be woken by the next pause.
+    fn pause_waiter(&self) -> Waiter<'_> {
+        Waiter {
+            list: &self.waiters.pause,
+            key: None,
+        }
+    }
+
+    /// Like `poll_paused`, registering the task through `waiter`.
+    fn poll_paused_with(&self, waiter: &mut Waiter<'_>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        waiter.register(cx.waker());
+
+        // The clock may have been paused before the waker was registered.
+        if self.is_paused() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Returns the number of times the clock has been paused.
+    pub(crate) fn pause_count(&self) -> u64 {
+        self.waiters.paused_time.lock().unwrap().pauses
+    }
+
+    /// Returns the real instant at which the current pause began, if the
+    /// clock is paused.
+    pub(crate) fn paused_since(&self) -> Option<std::time::Instant> {
+        self.waiters.paused_time.lock().unwrap().since
+    }
+
+    /// Registers the task to be notified on the next pause under the key
+    /// stored in `key`, handing out a key first if there is none.
+    pub(crate) fn register_pause_waiter(&self, key: &mut Option<u64>, waker: &Waker) {
+        let mut pause = self.waiters.pause.lock().unwrap();
+        let key = *key.get_or_insert_with(|| pause.next_key());
+
+        pause.register(Some(key), waker);
+    }
+
+    /// Withdraws the registration made by `register_pause_waiter`.
+    pub(crate) fn unregister_pause_waiter(&self, key: u64) {
+        self.waiters.pause.lock().unwrap().remove(key);
+    }
+
+    /// Blocks the current thread until the clock is resumed or `timeout` of
+    /// real time has elapsed, returning `false` on timeout. A timeout too
+    /// large to be added to the current instant never elapses.
+    #[cfg(feature = "rt")]
+    pub(crate) fn wait_for_resume_timeout(&self, timeout: std::time::Duration) -> bool {
+        use crate::park::thread::CachedParkThread;
+        use crate::park::Park;
+
+        let deadline = std::time::Instant::now().checked_add(timeout);
+        let mut park = CachedParkThread::new();
+        let waker = park
+            .get_unpark()
+            .expect("failed to park thread")
+            .into_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            if self.poll_resumed(&mut cx).is_ready() {
+                return true;
+            }
+
+            let deadline = match deadline {
+                Some(deadline) => deadline,
+                None => {
+                    park.park().expect("failed to park thread");
+                    continue;
+                }
+            };
+
+            let now = std::time::Instant::now();
+
+            if now >= deadline {
+                return false;
+            }
+
+            park.park_timeout(deadline - now)
+                .expect("failed to park thread");
+        }
+    }
+
+    /// Polls for the clock to be resumed, registering the task to be notified
+    /// on the next resume if it is currently paused.
+    pub(crate) fn poll_resumed(&self, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        self.waiters.register_resume(cx.waker());
+
+        // The clock may have been resumed before the waker was registered.
+        if self.is_paused() {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    /// Returns a registration to be woken by the next resume.
+    fn resume_waiter(&self) -> Waiter<'_> {
+        Waiter {
+            list: &self.waiters.resume,
+            key: None,
+        }
+    }
+
+    /// Returns the number of times the clock has been advanced.
+    fn advance_count(&self) -> usize {
+        self.waiters
+            .advances
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn advance_waiter(&self) -> Waiter<'_> {
+        Waiter {
+            list: &self.waiters.advance,
+            key: None,
+        }
+    }
+
+    /// Polls for the clock to be advanced since it was advanced `advances`
+    /// times, or to run, registering the task through both waiters.
+    fn poll_advanced_with(
+        &self,
+        advances: usize,
+        advance: &mut Waiter<'_>,
+        resume: &mut Waiter<'_>,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        if self.advance_count() != advances || !self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        advance.register(cx.waker());
+        resume.register(cx.waker());
+
+        // The clock may have been advanced or resumed before the waker was
+        // registered.
+        if self.advance_count() != advances || !self.is_paused() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Like `poll_resumed`, registering the task through `waiter`.
+    fn poll_resumed_with(&self, waiter: &mut Waiter<'_>, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        waiter.register(cx.waker());
+
+        // The clock may have been resumed before the waker was registered.
+        if self.is_paused() {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
 
 cfg_not_test_util! {
-    use crate::time::{Duration, Instant};
-    use std::sync::{Arc, atomic::Ordering};
+    use crate::loom::sync::atomic::AtomicU64;
+    use crate::time::{Duration, Instant, PausableTimeSource, SystemTimeSource, TimeSource};
+    use std::convert::TryFrom;
+    use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
     use pausable_clock::PausableClock;
 
-    #[derive(Debug, Clone)]
-    pub(crate) struct Clock {
-        pausable: bool,
-        pausing_clock: Arc<PausableClock>
-    }
+    #[derive(Debug, Clone)]
+    pub(crate) struct Clock {
+        pausable: bool,
+
+        /// Tracks whether the clock is paused, and holds it paused or running
+        /// for `run_if_paused` and the like. The time is read from `source`.
+        pausing_clock: Arc<PausableClock>,
+
+        /// Nanoseconds the clock has been advanced by while paused, on top of
+        /// the time read from `source`.
+        advanced: Arc<AtomicU64>,
+
+        /// Number of calls to `pause` not yet balanced by a call to `resume`.
+        depth: Arc<Mutex<usize>>,
+
+        /// Mirror of whether `pausing_clock` is paused, updated with `depth`
+        /// held on each transition, so that the state can be read with a
+        /// single relaxed load.
+        paused: Arc<AtomicBool>,
+
+        /// Rate of logical time relative to real time, if not `1.0`.
+        scale: Option<Scale>,
+
+        /// Source of the clock's time, paused and resumed along with
+        /// `pausing_clock`. This is a `PausableTimeSource` unless the runtime
+        /// was built with another, and a `SystemTimeSource` for clocks that
+        /// are not pausable.
+        source: Arc<dyn TimeSource>,
+
+        /// Instant returned by `now` when the clock was created.
+        base: std::time::Instant,
+
+        /// Latest instant returned by `now`, in nanoseconds since `base`.
+        latest: Arc<AtomicU64>,
+
+        /// Logical time reported as already elapsed at `base`.
+        elapsed_time: Duration,
+
+        waiters: Arc<Waiters>,
+    }
+
+    /// Rate at which logical time runs relative to real time.
+    #[derive(Debug, Clone, Copy)]
+    struct Scale {
+        factor: f64,
+
+        /// Unscaled instant of `source` from which scaling applies.
+        origin: std::time::Instant,
+
+        /// Unscaled elapsed milliseconds of the clock at `origin`.
+        origin_millis: u64,
+    }
+
+    impl Scale {
+        /// Returns the scaled logical time elapsed between `origin` and the
+        /// unscaled instant `now`. The builder bounds `factor` by
+        /// `MAX_TIME_SCALE`, so this does not overflow.
+        fn apply(&self, now: std::time::Instant) -> Duration {
+            now.saturating_duration_since(self.origin).mul_f64(self.factor)
+        }
+    }
+
+    /// Return the current instant according to the runtime's clock, factoring
+    /// in paused time.
+    pub(crate) fn now() -> Instant {
+        with_clock(|clock| match clock {
+            Some(clock) => clock.now(),
+            None => Instant::from_std(std::time::Instant::now()),
+        })
+    }
+
+    impl Clock {
+
+        pub(crate) fn is_test() -> bool {
+            false
+        }
+
+        pub(crate) fn new() -> Clock {
+            Clock {
+                pausable: false,
+                pausing_clock: Arc::new(PausableClock::default()),
+                advanced: Arc::new(AtomicU64::new(0)),
+                depth: Arc::new(Mutex::new(0)),
+                paused: Arc::new(AtomicBool::new(false)),
+                scale: None,
+                source: Arc::new(SystemTimeSource::new()),
+                base: std::time::Instant::now(),
+                latest: Arc::new(AtomicU64::new(0)),
+                elapsed_time: Duration::from_secs(0),
+                waiters: Arc::new(Waiters::default()),
+            }
+        }
+
+        pub(crate) fn new_pausable(paused: bool, elapsed_time: std::time::Duration) -> Clock {
+            Self::try_new_pausable(paused, elapsed_time)
+                .expect("elapsed time is too large for the platform's clock")
+        }
+
+        /// Like `new_pausable`, returning `None` if `elapsed_time` is too
+        /// large for the clock to represent on this platform.
+        pub(crate) fn try_new_pausable(paused: bool, elapsed_time: std::time::Duration) -> Option<Clock> {
+            // The pausable clock offsets the system's instants by the elapsed
+            // time and counts elapsed milliseconds in a `u64`, either of
+            // which would overflow deep within the clock.
+            let now = std::time::Instant::now();
+            now.checked_sub(elapsed_time)?;
+            now.checked_add(elapsed_time)?;
+            u64::try_from(elapsed_time.as_millis()).ok()?;
+
+            let source = PausableTimeSource::starting(paused);
+
+            let clock = Clock {
+                pausable: true,
+                base: source.now(),
+                latest: Arc::new(AtomicU64::new(0)),
+                pausing_clock: Arc::new(PausableClock::new(elapsed_time, paused)),
+                advanced: Arc::new(AtomicU64::new(0)),
+                depth: Arc::new(Mutex::new(paused as usize)),
+                paused: Arc::new(AtomicBool::new(paused)),
+                scale: None,
+                source: Arc::new(source),
+                elapsed_time,
+                waiters: Arc::new(Waiters::default()),
+            };
+
+            if paused {
+                clock.waiters.record_pause();
+            }
+
+            Some(clock)
+        }
+
+        pub(crate) fn pausable(&self) -> bool {
+            self.pausable
+        }
+
+        /// Makes logical time run at `factor` times the rate of real time
+        /// from now on.
+        pub(crate) fn with_time_scale(mut self, factor: f64) -> Clock {
+            assert!(self.pausable, "Not pausable");
+
+            let origin = self.source.now();
+            let elapsed = self.unscaled_elapsed(origin);
+
+            self.scale = Some(Scale {
+                factor,
+                origin,
+                origin_millis: crate::time::ms(elapsed, crate::time::Round::Down),
+            });
+            self
+        }
+
+        /// Makes the clock read its time from `source` instead of its
+        /// `PausableTimeSource`. `source` is paused and resumed along with the
+        /// clock from now on.
+        pub(crate) fn with_time_source(mut self, source: Arc<dyn TimeSource>) -> Clock {
+            assert!(self.pausable, "Not pausable");
+
+            if self.is_paused() {
+                source.pause();
+            }
+
+            self.base = source.now();
+            self.latest = Arc::new(AtomicU64::new(0));
+            self.source = source;
+            self
+        }
+
+        /// Returns the current instant of the clock.
+        ///
+        /// Non-pausable clocks read the system clock directly. This is the
+        /// path taken by every timer of a runtime that was not built with a
+        /// pausable clock, so it is kept to a single, inlined check; the
+        /// bookkeeping of pausable clocks lives out of line.
+        ///
+        /// A separate clock type for runtimes that are not pausable would
+        /// drop the check, but reading the system clock costs some 30ns, and
+        /// the check did not measurably add to it.
+        #[inline]
+        pub(crate) fn now(&self) -> Instant {
+            if !self.pausable {
+                return Instant::from_std(std::time::Instant::now());
+            }
+
+            self.pausable_now()
+        }
+
+        #[inline(never)]
+        fn pausable_now(&self) -> Instant {
+            let now = self.source.now();
+
+            let now = match self.scale {
+                Some(scale) => scale.origin + scale.apply(now),
+                None => now,
+            };
+
+            let since_base = (now + self.advanced()).saturating_duration_since(self.base);
+            Instant::from_std(self.base + self.not_before_latest(since_base))
+        }
+
+        /// Returns the logical time elapsed at the unscaled, unadvanced
+        /// instant `now` of the source, plus the elapsed time the clock was
+        /// built with.
+        fn unscaled_elapsed(&self, now: std::time::Instant) -> Duration {
+            now.saturating_duration_since(self.base) + self.elapsed_time
+        }
+
+        /// Records `since_base` as returned by `now`, returning it, or the
+        /// latest value returned if that is greater.
+        ///
+        /// The time of `source` and the offset added by `advance` are read
+        /// separately, and `source` is paused and resumed after the clock's
+        /// state changes. A reading racing with an
+        /// advance or a transition can thus combine values from before and
+        /// after it, and must not come out behind a reading returned before.
+        fn not_before_latest(&self, since_base: Duration) -> Duration {
+            let nanos = u64::try_from(since_base.as_nanos()).unwrap_or(u64::MAX);
+            let mut latest = self.latest.load(Ordering::SeqCst);
+
+            while nanos > latest {
+                let actual = self.latest.compare_and_swap(latest, nanos, Ordering::SeqCst);
+
+                if actual == latest {
+                    return since_base;
+                }
+
+                latest = actual;
+            }
+
+            Duration::from_nanos(latest)
+        }
+
+        /// Returns the real time it takes for `logical` time to elapse while
+        /// the clock is running, or `None` if logical time does not move.
+        pub(crate) fn real_duration(&self, logical: Duration) -> Option<Duration> {
+            match self.scale {
+                Some(scale) if scale.factor <= 0.0 => None,
+                Some(scale) => {
+                    let secs = logical.as_secs_f64() / scale.factor;
+
+                    if secs < u64::MAX as f64 {
+                        Some(Duration::from_secs_f64(secs))
+                    } else {
+                        None
+                    }
+                }
+                None => Some(logical),
+            }
+        }
+
+        /// Returns the total duration the clock has been advanced by.
+        fn advanced(&self) -> Duration {
+            Duration::from_nanos(self.advanced.load(Ordering::SeqCst))
+        }
+
+        /// Logical time since the clock was created, plus the elapsed time
+        /// it was built with.
+        pub(crate) fn elapsed(&self) -> Duration {
+            self.now().into_std().saturating_duration_since(self.base) + self.elapsed_time
+        }
+
+        /// Milliseconds of logical time since the clock was created, plus the
+        /// elapsed time it was built with. Non-pausable clocks are never
+        /// paused, so for them this is the real time since creation.
+        pub(crate) fn elapsed_millis(&self) -> u64 {
+            let advanced = crate::time::ms(self.advanced(), crate::time::Round::Down);
+
+            let now = self.source.now();
+
+            let elapsed = match self.scale {
+                Some(scale) => {
+                    let scaled = scale.apply(now);
+                    scale.origin_millis + crate::time::ms(scaled, crate::time::Round::Down)
+                }
+                None => crate::time::ms(self.unscaled_elapsed(now), crate::time::Round::Down),
+            };
+
+            elapsed + advanced
+        }
+
+        pub(crate) fn is_paused(&self) -> bool {
+            self.is_paused_ordered(Ordering::SeqCst)
+        }
+
+        pub(crate) fn is_paused_ordered(&self, ordering: Ordering) -> bool {
+            if self.pausable {
+                self.pausing_clock.is_paused_ordered(ordering)
+            }
+            else {
+                false
+            }
+        }
 
-    pub(crate) fn now() -> Instant {
-        Instant::from_std(std::time::Instant::now())
-    }
+        /// Reads the mirror of the paused state, which non-pausable clocks
+        /// never set.
+        pub(crate) fn is_paused_relaxed(&self) -> bool {
+            self.paused.load(Ordering::Relaxed)
+        }
 
-    impl Clock {
+        pub(crate) fn advance(&self, duration: Duration) {
+            self.waiters.check_not_warping();
 
-        pub(crate) fn is_test() -> bool {
-            false
+            match self.try_advance(duration) {
+                Ok(()) => {}
+                Err(ClockError::NotPausable) => panic!("Not pausable"),
+                Err(ClockError::Overflow) => panic!("advancing the clock overflowed"),
+                Err(_) => panic!("time is not frozen"),
+            }
         }
 
-        pub(crate) fn new() -> Clock {
-            Clock {
-                pausable: false,
-                pausing_clock: Arc::new(PausableClock::default())
+        pub(crate) fn try_advance(&self, duration: Duration) -> Result<(), ClockError> {
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
+
+            self.waiters.try_check_not_warping()?;
+            let (old, new) = self.advance_offset(duration)?;
+
+            self.waiters.unpark_drivers();
+            self.waiters.warped(old, new);
+            Ok(())
         }
 
-        pub(crate) fn new_pausable(paused: bool, elapsed_time: std::time::Duration) -> Clock {
-            Clock {
-                pausable: true,
-                pausing_clock: Arc::new(PausableClock::new(elapsed_time, paused))
+        pub(crate) fn try_advance_to(&self, target: Instant) -> Result<(), ClockError> {
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
+
+            self.waiters.try_check_not_warping()?;
+
+            // The frozen instant cannot change between reading it and
+            // updating the offset, unless the clock is advanced concurrently.
+            let now = self
+                .pausing_clock
+                .run_if_paused(|| {
+                    let now = self.now();
+
+                    if target < now {
+                        return Err(ClockError::InPast);
+                    }
+
+                    self.add_advanced(target - now)?;
+                    Ok(now)
+                })
+                .ok_or(ClockError::NotPaused)??;
+
+            self.waiters.unpark_drivers();
+            self.waiters.warped(now, target);
+            Ok(())
         }
 
-        pub(crate) fn pausable(&self) -> bool {
-            self.pausable
+        /// Adds `duration` to the offset of the paused clock, returning its
+        /// frozen instant before and after.
+        fn advance_offset(&self, duration: Duration) -> Result<(Instant, Instant), ClockError> {
+            // Hold the clock paused while the offset is updated so that the
+            // frozen instant only ever moves forward by `duration`.
+            self.pausing_clock
+                .run_if_paused(|| {
+                    let old = self.now();
+                    self.add_advanced(duration)?;
+                    Ok((old, self.now()))
+                })
+                .ok_or(ClockError::NotPaused)?
         }
 
-        pub(crate) fn now(&self) -> Instant {
-            if self.pausable {
-                Instant::from_std(self.pausing_clock.now_std())
-            }
-            else {
-                now()
+        /// Adds `duration` to the offset, leaving it unchanged if the total
+        /// would no longer fit.
+        fn add_advanced(&self, duration: Duration) -> Result<(), ClockError> {
+            let nanos = u64::try_from(duration.as_nanos()).map_err(|_| ClockError::Overflow)?;
+            let mut advanced = self.advanced.load(Ordering::SeqCst);
+
+            loop {
+                let next = advanced.checked_add(nanos).ok_or(ClockError::Overflow)?;
+                let actual = self.advanced.compare_and_swap(advanced, next, Ordering::SeqCst);
+
+                if actual == advanced {
+                    return Ok(());
+                }
+
+                advanced = actual;
             }
         }
 
-        pub(crate) fn elapsed_millis(&self) -> u64 {
-            if self.pausable {
-                self.pausing_clock.now().elapsed_millis()
+        pub(crate) fn pause_depth(&self) -> usize {
+            *self.depth.lock().unwrap()
+        }
+
+        pub(crate) fn pause(&self) -> bool {
+            self.pause_with(|| ()).0
+        }
+
+        /// Pauses the clock like `pause`, then runs `action` before the clock
+        /// can be resumed and before pause callbacks and waiters are
+        /// notified.
+        pub(crate) fn pause_with<T,F>(&self, action: F) -> (bool, T)
+            where F : FnOnce() -> T
+        {
+            if !self.pausable {
+                panic!("Not pausable");
             }
-            else {
-                panic!("elapsed time is not supported for non-pausable clocks")
+
+            self.waiters.check_not_warping();
+
+            let (paused, output) = {
+                let mut depth = self.depth.lock().unwrap();
+                *depth += 1;
+
+                let paused = *depth == 1 && self.freeze();
+                (paused, action())
+            };
+
+            if paused {
+                self.notify_paused();
             }
+
+            (paused, output)
         }
 
-        pub(crate) fn is_paused(&self) -> bool {
-            if self.pausable {
-                self.pausing_clock.is_paused()
+        /// Pauses the clock unless it is already paused, in which case the
+        /// pause depth is left untouched.
+        pub(crate) fn try_pause(&self) -> Result<bool, ClockError> {
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
-            else {
-                false
+
+            self.waiters.try_check_not_warping()?;
+
+            let paused = {
+                let mut depth = self.depth.lock().unwrap();
+
+                if *depth > 0 {
+                    return Err(ClockError::AlreadyPaused);
+                }
+
+                *depth = 1;
+                self.freeze()
+            };
+
+            if paused {
+                self.notify_paused();
             }
+
+            Ok(paused)
         }
 
-        pub(crate) fn is_paused_ordered(&self, ordering: Ordering) -> bool {
-            if self.pausable {
-                self.pausing_clock.is_paused_ordered(ordering)
+        /// Pauses the time source and `pausing_clock`, and updates the
+        /// mirror of its state. The caller holds the depth lock, so the
+        /// state cannot change underneath it.
+        ///
+        /// The source is paused first, so that the clock never reads as
+        /// paused while its time still moves.
+        fn freeze(&self) -> bool {
+            if self.pausing_clock.is_paused_ordered(Ordering::SeqCst) {
+                return false;
             }
-            else {
-                false
+
+            self.source.pause();
+            self.pausing_clock.pause();
+            self.paused.store(true, Ordering::Release);
+            true
+        }
+
+        /// Resumes `pausing_clock` and the time source, and updates the
+        /// mirror of its state. The caller holds the depth lock.
+        ///
+        /// The source is resumed last, once nothing can be holding the clock
+        /// paused anymore.
+        fn unfreeze(&self) -> bool {
+            if !self.pausing_clock.is_paused_ordered(Ordering::SeqCst) {
+                return false;
+            }
+
+            self.pausing_clock.resume();
+            self.paused.store(false, Ordering::Release);
+            self.source.resume();
+            true
+        }
+
+        fn notify_paused(&self) {
+            if let Some(f) = &self.waiters.on_pause {
+                self.pausing_clock.run_unresumable(|| f());
             }
+
+            self.waiters.paused();
+            self.trace_transition(true);
+            self.waiters.unpark_drivers();
         }
 
-        pub(crate) fn advance(&self, _dur: Duration) {
-            unreachable!();
+        pub(crate) fn resume(&self) -> bool {
+            self.resume_measured().is_some()
         }
 
-        pub(crate) fn pause(&self) -> bool {
+        /// Like `resume`, returning the real time spent in the pause that
+        /// this call ended, or `None` if the clock is not running again.
+        pub(crate) fn resume_measured(&self) -> Option<std::time::Duration> {
             if self.pausable {
-                self.pausing_clock.pause()
+                self.waiters.check_not_warping();
+
+                let resumed = {
+                    let mut depth = self.depth.lock().unwrap();
+
+                    match *depth {
+                        0 => false,
+                        1 => {
+                            *depth = 0;
+                            self.unfreeze()
+                        }
+                        _ => {
+                            *depth -= 1;
+                            false
+                        }
+                    }
+                };
+
+                if resumed {
+                    Some(self.notify_resumed())
+                } else {
+                    None
+                }
             }
             else {
                 panic!("Not pausable");
             }
         }
 
-        pub(crate) fn resume(&self) -> bool {
-            if self.pausable {
-                self.pausing_clock.resume()
+        /// Advances the paused clock by `duration` and balances one pause,
+        /// with no other pause or resume in between.
+        pub(crate) fn resume_with_advance(&self, duration: Duration) -> Result<bool, ClockError> {
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
-            else {
-                panic!("Not pausable");
+
+            self.waiters.try_check_not_warping()?;
+
+            let (resumed, old, new) = {
+                let mut depth = self.depth.lock().unwrap();
+
+                if *depth == 0 {
+                    return Err(ClockError::NotPaused);
+                }
+
+                let (old, new) = self.advance_offset(duration)?;
+                *depth -= 1;
+
+                (*depth == 0 && self.unfreeze(), old, new)
+            };
+
+            self.waiters.unpark_drivers();
+            self.waiters.warped(old, new);
+
+            if resumed {
+                self.notify_resumed();
+            }
+
+            Ok(resumed)
+        }
+
+        fn notify_resumed(&self) -> std::time::Duration {
+            if let Some(f) = &self.waiters.on_resume {
+                self.pausing_clock.run_unpausable(|| f());
+            }
+
+            let paused_for = self.waiters.resumed();
+            self.trace_transition(false);
+            self.waiters.unpark_drivers();
+
+            paused_for
+        }
+
+        pub(crate) fn try_resume(&self) -> Result<bool, ClockError> {
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
+
+            self.waiters.try_check_not_warping()?;
+            Ok(self.resume())
+        }
+
+        /// Returns the frozen instant, holding the clock paused while it is
+        /// read.
+        pub(crate) fn frozen_now(&self) -> Option<Instant> {
+            self.run_if_paused(|| self.now())
         }
 
         pub(crate) fn run_unpausable<T,F>(&self, action: F) -> T
@@ -114,15 +2548,19 @@ cfg_not_test_util! {
             }
         }
 
-        pub(crate) fn run_unresumable<T,F>(&self, action: F) -> T
+        /// Runs `action` while preventing the clock from being resumed,
+        /// blocking until the clock is paused if it is running.
+        ///
+        /// Non-pausable clocks are never paused, so this would block forever
+        /// and errors instead.
+        pub(crate) fn run_unresumable<T,F>(&self, action: F) -> Result<T, ClockError>
             where F : FnOnce() -> T
         {
-            if self.pausable {
-                self.pausing_clock.run_unresumable(action)
-            }
-            else {
-                unreachable!("I think this is better than blocking forever");
+            if !self.pausable {
+                return Err(ClockError::NotPausable);
             }
+
+            Ok(self.pausing_clock.run_unresumable(action))
         }
 
         pub(crate) fn run_if_resumed<T,F>(&self, action: F) -> Option<T>
@@ -162,35 +2600,100 @@ cfg_not_test_util! {
 }
 
 cfg_test_util! {
+    use crate::loom::sync::atomic::AtomicU64;
     use crate::time::{Duration, Instant};
-    use std::sync::{ Arc, Mutex, atomic::Ordering };
-    use crate::runtime::context;
+    use std::convert::TryFrom;
+    use std::sync::{ Arc, atomic::Ordering };
 
     /// A handle to a source of time.
     #[derive(Debug, Clone)]
     pub(crate) struct Clock {
-        inner: Arc<Mutex<Inner>>,
+        inner: Arc<Inner>,
+        waiters: Arc<Waiters>,
     }
 
-    cfg_rt! {
-        fn clock() -> Option<Clock> {
-            crate::runtime::context::clock()
-        }
+    /// Clock state is packed into a single `AtomicU64` so that `now()` never
+    /// takes a lock.
+    ///
+    /// All values are nanoseconds measured from `Inner::origin`, modulo
+    /// `VALUE + 1`. While frozen, the value is the frozen logical time. While
+    /// unfrozen, it is the skew to add to the real time to get the logical
+    /// time.
+    ///
+    /// Writers set `BUSY` before sampling the real time, and readers retry if
+    /// the state changed while they were sampling it. This keeps `now()`
+    /// monotonic across a concurrent `pause` or `resume`.
+    const FROZEN: u64 = 1 << 63;
+    const BUSY: u64 = 1 << 62;
+    const VALUE: u64 = BUSY - 1;
+
+    #[derive(Debug)]
+    struct Inner {
+        /// Instant that both real and logical time are measured from.
+        origin: std::time::Instant,
+
+        /// Packed `FROZEN`, `BUSY`, and value bits.
+        state: AtomicU64,
+
+        /// Number of calls to `pause` not yet balanced by a call to `resume`.
+        ///
+        /// The lock also serializes writers of `state`.
+        depth: Mutex<usize>,
     }
 
-    cfg_not_rt! {
-        fn clock() -> Option<Clock> {
-            None
+    impl Inner {
+        /// Nanoseconds of real time since `origin`.
+        fn real(&self) -> u64 {
+            self.origin.elapsed().as_nanos() as u64 & VALUE
         }
-    }
 
-    #[derive(Debug)]
-    struct Inner {
-        /// Instant to use as the clock's base instant.
-        base: std::time::Instant,
+        /// Loads a state that no writer is in the middle of updating.
+        fn load(&self, ordering: Ordering) -> u64 {
+            loop {
+                let state = self.state.load(ordering);
+
+                if state & BUSY == 0 {
+                    return state;
+                }
 
-        /// Instant at which the clock was last unfrozen
-        unfrozen: Option<std::time::Instant>,
+                std::thread::yield_now();
+            }
+        }
+
+        /// Nanoseconds of logical time since `origin`.
+        fn logical(&self) -> u64 {
+            loop {
+                let state = self.load(Ordering::Acquire);
+
+                if state & FROZEN != 0 {
+                    return state & VALUE;
+                }
+
+                let logical = self.real().wrapping_add(state) & VALUE;
+
+                if self.state.load(Ordering::SeqCst) == state {
+                    return logical;
+                }
+            }
+        }
+
+        /// Atomically replaces an unfrozen state with a frozen one, or the
+        /// reverse. Must be called with the `depth` lock held.
+        fn toggle(&self, freeze: bool) {
+            let state = self.state.load(Ordering::Acquire);
+            assert_eq!(state & FROZEN == 0, freeze, "clock state mismatch");
+
+            self.state.store(state | BUSY, Ordering::SeqCst);
+            let real = self.real();
+
+            let next = if freeze {
+                FROZEN | (real.wrapping_add(state) & VALUE)
+            } else {
+                (state & VALUE).wrapping_sub(real) & VALUE
+            };
+
+            self.state.store(next, Ordering::Release);
+        }
     }
 
     /// Pause time
@@ -200,13 +2703,24 @@ cfg_test_util! {
     /// Once the timer wheel is checked, time will immediately advance to the next registered
     /// `Sleep`. This is useful for running tests that depend on time.
     ///
+    /// Pausing time that is already frozen does nothing, so a single call to
+    /// [`resume`] always lets time run again. This differs from
+    /// [`Handle::pause_time`], which counts nested pauses and needs as many
+    /// resumes to let time run again.
+    ///
     /// # Panics
     ///
-    /// Panics if time is already frozen or if called from outside of the Tokio
-    /// runtime.
+    /// Panics if called from outside of the Tokio runtime or from within an
+    /// `on_time_warp` callback.
+    ///
+    /// [`Handle::pause_time`]: crate::runtime::Handle::pause_time
     pub fn pause() {
         let clock = clock().expect("time cannot be frozen from outside the Tokio runtime");
-        clock.pause();
+
+        match clock.try_pause() {
+            Ok(_) | Err(ClockError::AlreadyPaused) => {}
+            Err(err) => panic!("{}", err),
+        }
     }
 
     /// Resume time
@@ -214,19 +2728,14 @@ cfg_test_util! {
     /// Clears the saved `Instant::now()` value. Subsequent calls to
     /// `Instant::now()` will return the value returned by the system call.
     ///
+    /// Resuming time that is not frozen does nothing.
+    ///
     /// # Panics
     ///
-    /// Panics if time is not frozen or if called from outside of the Tokio
-    /// runtime.
+    /// Panics if called from outside of the Tokio runtime.
     pub fn resume() {
         let clock = clock().expect("time cannot be frozen from outside the Tokio runtime");
-        let mut inner = clock.inner.lock().unwrap();
-
-        if inner.unfrozen.is_some() {
-            panic!("time is not frozen");
-        }
-
-        inner.unfrozen = Some(std::time::Instant::now());
+        clock.resume();
     }
 
 
@@ -240,31 +2749,18 @@ cfg_test_util! {
     /// Panics if time is not frozen or if called from outside of the Tokio
     /// runtime.
     pub async fn advance(duration: Duration) {
-        use crate::future::poll_fn;
-        use std::task::Poll;
-
         let clock = clock().expect("time cannot be frozen from outside the Tokio runtime");
         clock.advance(duration);
 
-        let mut yielded = false;
-        poll_fn(|cx| {
-            if yielded {
-                Poll::Ready(())
-            } else {
-                yielded = true;
-                cx.waker().wake_by_ref();
-                Poll::Pending
-            }
-        }).await;
+        yield_once().await;
     }
 
     /// Return the current instant, factoring in frozen time.
     pub(crate) fn now() -> Instant {
-        if let Some(clock) = clock() {
-            clock.now()
-        } else {
-            Instant::from_std(std::time::Instant::now())
-        }
+        with_clock(|clock| match clock {
+            Some(clock) => clock.now(),
+            None => Instant::from_std(std::time::Instant::now()),
+        })
     }
 
     impl Clock {
@@ -277,94 +2773,318 @@ cfg_test_util! {
         /// Return a new `Clock` instance that uses the current execution context's
         /// source of time.
         pub(crate) fn new() -> Clock {
-            let now = std::time::Instant::now();
-
             Clock {
-                inner: Arc::new(Mutex::new(Inner {
-                    base: now,
-                    unfrozen: Some(now),
-                })),
+                inner: Arc::new(Inner {
+                    origin: std::time::Instant::now(),
+                    state: AtomicU64::new(0),
+                    depth: Mutex::new(0),
+                }),
+                waiters: Arc::new(Waiters::default()),
             }
         }
 
-        #[allow(dead_code)]
-        pub(crate) fn new_pausable(_pausable: bool, _elapsed_time: std::time::Duration) -> Clock {
-            Self::new()
+        pub(crate) fn new_pausable(paused: bool, _elapsed_time: std::time::Duration) -> Clock {
+            let clock = Self::new();
+
+            if paused {
+                clock.pause();
+            }
+
+            clock
+        }
+
+        /// The `test-util` clock ignores the elapsed time, so it never fails.
+        pub(crate) fn try_new_pausable(paused: bool, elapsed_time: std::time::Duration) -> Option<Clock> {
+            Some(Self::new_pausable(paused, elapsed_time))
+        }
+
+        pub(crate) fn pausable(&self) -> bool {
+            true
+        }
+
+        pub(crate) fn pause_depth(&self) -> usize {
+            *self.inner.depth.lock().unwrap()
         }
 
         pub(crate) fn pause(&self) -> bool {
-            let mut inner = self.inner.lock().unwrap();
+            self.pause_with(|| ()).0
+        }
 
-            let elapsed = inner.unfrozen.as_ref().expect("time is already frozen").elapsed();
-            inner.base += elapsed;
-            inner.unfrozen = None;
+        /// Pauses the clock like `pause`, then runs `action` before the clock
+        /// can be resumed or advanced and before pause waiters are notified.
+        pub(crate) fn pause_with<T,F>(&self, action: F) -> (bool, T)
+            where F : FnOnce() -> T
+        {
+            self.waiters.check_not_warping();
 
-            true
+            let mut depth = self.inner.depth.lock().unwrap();
+
+            *depth += 1;
+
+            if *depth > 1 {
+                return (false, action());
+            }
+
+            self.inner.toggle(true);
+            let output = action();
+            drop(depth);
+
+            self.notify_paused();
+            (true, output)
+        }
+
+        /// Pauses the clock unless it is already paused, in which case the
+        /// pause depth is left untouched.
+        pub(crate) fn try_pause(&self) -> Result<bool, ClockError> {
+            self.waiters.try_check_not_warping()?;
+
+            let mut depth = self.inner.depth.lock().unwrap();
+
+            if *depth > 0 {
+                return Err(ClockError::AlreadyPaused);
+            }
+
+            *depth = 1;
+            self.inner.toggle(true);
+            drop(depth);
+
+            self.notify_paused();
+            Ok(true)
+        }
+
+        fn notify_paused(&self) {
+            if let Some(f) = &self.waiters.on_pause {
+                f();
+            }
+
+            self.waiters.paused();
+            self.trace_transition(true);
         }
 
         pub(crate) fn is_paused(&self) -> bool {
-            let inner = self.inner.lock().unwrap();
-            inner.unfrozen.is_none()
+            self.is_paused_ordered(Ordering::SeqCst)
+        }
+
+        pub(crate) fn is_paused_ordered(&self, ordering: Ordering) -> bool {
+            self.inner.load(ordering) & FROZEN != 0
         }
 
-        pub(crate) fn is_paused_ordered(&self, _: Ordering) -> bool {
-            self.is_paused()
+        /// The state is already a single atomic, so it needs no mirror.
+        pub(crate) fn is_paused_relaxed(&self) -> bool {
+            self.is_paused_ordered(Ordering::Relaxed)
         }
 
+        /// Balances one call to `pause`, returning `true` if time is running
+        /// again as a result. Resuming time that is not frozen does nothing.
         pub(crate) fn resume(&self) -> bool {
-            self.advance(Default::default());
-            true
+            self.resume_measured().is_some()
+        }
+
+        /// Like `resume`, returning the real time spent in the pause that
+        /// this call ended, or `None` if time is not running again.
+        pub(crate) fn resume_measured(&self) -> Option<std::time::Duration> {
+            self.waiters.check_not_warping();
+
+            let mut depth = self.inner.depth.lock().unwrap();
+
+            if *depth == 0 {
+                return None;
+            }
+
+            *depth -= 1;
+
+            if *depth > 0 {
+                return None;
+            }
+
+            self.inner.toggle(false);
+            drop(depth);
+
+            Some(self.notify_resumed())
+        }
+
+        /// Advances frozen time by `duration` and balances one pause, with no
+        /// other pause, resume or advance in between.
+        pub(crate) fn resume_with_advance(&self, duration: Duration) -> Result<bool, ClockError> {
+            self.waiters.try_check_not_warping()?;
+
+            let mut depth = self.inner.depth.lock().unwrap();
+
+            if *depth == 0 {
+                return Err(ClockError::NotPaused);
+            }
+
+            let (old, new) = self.advance_frozen(duration)?;
+            *depth -= 1;
+
+            if *depth > 0 {
+                drop(depth);
+
+                self.waiters.warped(old, new);
+                return Ok(false);
+            }
+
+            self.inner.toggle(false);
+            drop(depth);
+
+            self.waiters.warped(old, new);
+            self.notify_resumed();
+            Ok(true)
+        }
+
+        fn notify_resumed(&self) -> std::time::Duration {
+            if let Some(f) = &self.waiters.on_resume {
+                f();
+            }
+
+            let paused_for = self.waiters.resumed();
+            self.trace_transition(false);
+
+            paused_for
+        }
+
+        pub(crate) fn try_resume(&self) -> Result<bool, ClockError> {
+            self.waiters.try_check_not_warping()?;
+            Ok(self.resume())
         }
 
         pub(crate) fn advance(&self, duration: Duration) {
-            let mut inner = self.inner.lock().unwrap();
+            self.waiters.check_not_warping();
+
+            match self.try_advance(duration) {
+                Ok(()) => {}
+                Err(ClockError::Overflow) => panic!("advancing the clock overflowed"),
+                Err(_) => panic!("time is not frozen"),
+            }
+        }
+
+        pub(crate) fn try_advance(&self, duration: Duration) -> Result<(), ClockError> {
+            self.waiters.try_check_not_warping()?;
+
+            let (old, new) = {
+                let _depth = self.inner.depth.lock().unwrap();
+                self.advance_frozen(duration)?
+            };
+
+            self.waiters.warped(old, new);
+            Ok(())
+        }
+
+        pub(crate) fn try_advance_to(&self, target: Instant) -> Result<(), ClockError> {
+            self.waiters.try_check_not_warping()?;
+
+            let (old, new) = {
+                let _depth = self.inner.depth.lock().unwrap();
+                let now = self.frozen_now().ok_or(ClockError::NotPaused)?;
 
-            if inner.unfrozen.is_some() {
-                panic!("time is not frozen");
+                if target < now {
+                    return Err(ClockError::InPast);
+                }
+
+                self.advance_frozen(target - now)?
+            };
+
+            self.waiters.warped(old, new);
+            Ok(())
+        }
+
+        /// Advances frozen time by `duration`, returning the frozen instant
+        /// before and after. The caller holds the depth lock.
+        fn advance_frozen(&self, duration: Duration) -> Result<(Instant, Instant), ClockError> {
+            let state = self.inner.state.load(Ordering::Acquire);
+
+            if state & FROZEN == 0 {
+                return Err(ClockError::NotPaused);
             }
 
-            inner.base += duration;
+            let logical = u64::try_from(duration.as_nanos())
+                .ok()
+                .and_then(|nanos| (state & VALUE).checked_add(nanos))
+                .filter(|&logical| logical <= VALUE)
+                .ok_or(ClockError::Overflow)?;
+            self.inner.state.store(FROZEN | logical, Ordering::Release);
+
+            let instant = |nanos| Instant::from_std(self.inner.origin + Duration::from_nanos(nanos));
+            Ok((instant(state & VALUE), instant(logical)))
+        }
+
+        /// The test clock always runs at the rate of real time; the builder
+        /// rejects any other scale.
+        pub(crate) fn with_time_scale(self, _factor: f64) -> Clock {
+            self
+        }
+
+        pub(crate) fn with_time_source(self, _source: Arc<dyn crate::time::TimeSource>) -> Clock {
+            self
         }
 
         pub(crate) fn now(&self) -> Instant {
-            let inner = self.inner.lock().unwrap();
+            Instant::from_std(self.inner.origin + Duration::from_nanos(self.inner.logical()))
+        }
+
+        pub(crate) fn real_duration(&self, logical: Duration) -> Option<Duration> {
+            Some(logical)
+        }
+
+        pub(crate) fn elapsed(&self) -> Duration {
+            Duration::from_nanos(self.inner.logical())
+        }
 
-            let mut ret = inner.base;
+        /// Returns the frozen instant from a single load of the state, so
+        /// that it cannot be torn by a concurrent resume.
+        pub(crate) fn frozen_now(&self) -> Option<Instant> {
+            let state = self.inner.load(Ordering::SeqCst);
 
-            if let Some(unfrozen) = inner.unfrozen {
-                ret += unfrozen.elapsed();
+            if state & FROZEN == 0 {
+                return None;
             }
 
-            Instant::from_std(ret)
+            Some(Instant::from_std(self.inner.origin + Duration::from_nanos(state & VALUE)))
         }
 
         pub(crate) fn elapsed_millis(&self) -> u64 {
-            unreachable!("Not implemented for tests");
+            crate::time::ms(self.elapsed(), crate::time::Round::Down)
         }
 
-        #[allow(dead_code)]
         pub(crate) fn run_unpausable<T,F>(&self, action: F) -> T
             where F : FnOnce() -> T
         {
             action()
         }
 
-        pub(crate) fn run_unresumable<T,F>(&self, action: F) -> T
+        /// Frozen time can be resumed by any thread at any time, there is no
+        /// way to hold it off while `action` runs.
+        pub(crate) fn run_unresumable<T,F>(&self, _action: F) -> Result<T, ClockError>
             where F : FnOnce() -> T
         {
-            unreachable!("Not implemented for tests");
+            Err(ClockError::Unsupported)
         }
 
+        /// Transitions are made with the `depth` lock held, so holding it
+        /// keeps the clock in its current state while `action` runs.
         pub(crate) fn run_if_resumed<T,F>(&self, action: F) -> Option<T>
             where F : FnOnce() -> T
         {
+            let _depth = self.inner.depth.lock().unwrap();
+
+            if self.inner.load(Ordering::Acquire) & FROZEN != 0 {
+                return None;
+            }
+
             Some(action())
         }
 
+        /// See `run_if_resumed`.
         pub(crate) fn run_if_paused<T,F>(&self, action: F) -> Option<T>
             where F : FnOnce() -> T
         {
-            None
+            let _depth = self.inner.depth.lock().unwrap();
+
+            if self.inner.load(Ordering::Acquire) & FROZEN == 0 {
+                return None;
+            }
+
+            Some(action())
         }
 
         pub(crate) fn wait_for_resume(&self) {
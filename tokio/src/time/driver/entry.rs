@@ -45,6 +45,14 @@ pub(crate) struct Entry {
     /// instant, this value is changed.
     state: AtomicU64,
 
+    /// Order in which the entry was registered with the timer, renewed each
+    /// time it is reset. Entries sharing a deadline are fired in this order.
+    seq: AtomicU64,
+
+    /// True if the deadline is measured on `std::time::Instant` rather than
+    /// on the clock, so that the entry fires whether or not it is paused.
+    real: bool,
+
     /// Stores the actual error. If `state` indicates that an error occurred,
     /// this is guaranteed to be a non-zero value representing the first error
     /// that occurred. Otherwise its value is undefined.
@@ -111,21 +119,37 @@ const ERROR: u64 = u64::MAX;
 
 impl Entry {
     pub(crate) fn new(handle: &Handle, deadline: Instant, duration: Duration) -> Arc<Entry> {
+        Entry::register(handle, deadline, duration, false)
+    }
+
+    /// Creates an entry that fires at the real instant `deadline`, whether or
+    /// not the clock is paused in the meantime.
+    pub(crate) fn new_real(handle: &Handle, deadline: std::time::Instant) -> Arc<Entry> {
+        Entry::register(
+            handle,
+            Instant::from_std(deadline),
+            Duration::from_millis(0),
+            true,
+        )
+    }
+
+    fn register(handle: &Handle, deadline: Instant, duration: Duration, real: bool) -> Arc<Entry> {
         let inner = handle.inner().unwrap();
 
         // Attempt to increment the number of active timeouts
         let entry = if let Err(err) = inner.increment() {
-            let entry = Entry::new2(deadline, duration, Weak::new(), ERROR);
+            let entry = Entry::new2(deadline, duration, Weak::new(), ERROR, 0, real);
             entry.error(err);
             entry
         } else {
-            let when = inner.normalize_deadline(deadline);
-            let state = if when <= inner.elapsed() {
+            let when = inner.normalize_deadline(deadline, real);
+            let state = if when <= inner.elapsed(real) {
                 ELAPSED
             } else {
                 when
             };
-            Entry::new2(deadline, duration, Arc::downgrade(&inner), state)
+            let seq = inner.next_seq();
+            Entry::new2(deadline, duration, Arc::downgrade(&inner), state, seq, real)
         };
 
         let entry = Arc::new(entry);
@@ -147,6 +171,16 @@ impl Entry {
         &mut *self.time.0.get()
     }
 
+    /// Returns the order in which the entry was last registered.
+    pub(crate) fn seq(&self) -> u64 {
+        self.seq.load(SeqCst)
+    }
+
+    /// Returns `true` if the deadline is measured on `std::time::Instant`.
+    pub(crate) fn is_real(&self) -> bool {
+        self.real
+    }
+
     pub(crate) fn when(&self) -> u64 {
         self.when_internal().expect("invalid internal state")
     }
@@ -279,8 +313,8 @@ impl Entry {
         };
 
         let deadline = entry.time_ref().deadline;
-        let when = inner.normalize_deadline(deadline);
-        let elapsed = inner.elapsed();
+        let when = inner.normalize_deadline(deadline, entry.real);
+        let elapsed = inner.elapsed(entry.real);
 
         let next = if when <= elapsed { ELAPSED } else { when };
 
@@ -304,6 +338,10 @@ impl Entry {
             curr = actual;
         }
 
+        // A reset timer is registered anew, behind those already registered
+        // for the same deadline.
+        entry.seq.store(inner.next_seq(), SeqCst);
+
         // If the state has transitioned to 'elapsed' then wake the task as
         // this entry is ready to be polled.
         if !is_elapsed(curr) && is_elapsed(next) {
@@ -318,12 +356,21 @@ impl Entry {
         }
     }
 
-    fn new2(deadline: Instant, duration: Duration, inner: Weak<Inner>, state: u64) -> Self {
+    fn new2(
+        deadline: Instant,
+        duration: Duration,
+        inner: Weak<Inner>,
+        state: u64,
+        seq: u64,
+        real: bool,
+    ) -> Self {
         Self {
             time: CachePadded(UnsafeCell::new(Time { deadline, duration })),
             inner,
             waker: AtomicWaker::new(),
             state: AtomicU64::new(state),
+            seq: AtomicU64::new(seq),
+            real,
             queued: AtomicBool::new(false),
             error: AtomicU8::new(0),
             next_atomic: UnsafeCell::new(ptr::null_mut()),
@@ -18,6 +18,72 @@ impl Handle {
     pub(crate) fn inner(&self) -> Option<Arc<Inner>> {
         self.inner.upgrade()
     }
+
+    /// Returns the earliest deadline of the timers registered with the
+    /// driver, or `None` if there is none.
+    pub(crate) fn next_deadline(&self) -> Option<crate::time::Instant> {
+        self.inner().and_then(|inner| inner.next_wake())
+    }
+
+    /// Returns the number of timers armed in the driver's wheel.
+    pub(crate) fn armed_timers(&self) -> usize {
+        self.inner().map_or(0, |inner| inner.armed())
+    }
+
+    /// Asks the driver to advance its paused clock to the next timer deadline
+    /// and fire that timer, completing once it has done so.
+    pub(crate) async fn step(&self) {
+        let inner = match self.inner() {
+            Some(inner) => inner,
+            None => return,
+        };
+
+        let ticket = inner.request_step();
+        crate::future::poll_fn(|cx| inner.poll_step(ticket, cx)).await
+    }
+
+    /// Performs up to `n` steps, stopping early once a step fires no timer.
+    /// Returns the number of timers fired.
+    ///
+    /// Timers fired by steps requested concurrently through other handles
+    /// are counted as well.
+    pub(crate) async fn step_n(&self, n: usize) -> usize {
+        let inner = match self.inner() {
+            Some(inner) => inner,
+            None => return 0,
+        };
+
+        let mut fired = 0;
+
+        for _ in 0..n {
+            let before = inner.steps_fired();
+            let ticket = inner.request_step();
+            crate::future::poll_fn(|cx| inner.poll_step(ticket, cx)).await;
+
+            match inner.steps_fired() - before {
+                0 => break,
+                n => fired += n as usize,
+            }
+        }
+
+        fired
+    }
+
+    /// Waits until the scheduler runs out of ready tasks and the driver has
+    /// fired the timers that are due then, after advancing a paused clock to
+    /// the next timer deadline. Returns the number of timers fired.
+    pub(crate) async fn idle(&self) -> u64 {
+        let inner = match self.inner() {
+            Some(inner) => inner,
+            None => return 0,
+        };
+
+        let before = inner.idle_fired();
+        let ticket = inner.request_idle();
+        crate::future::poll_fn(|cx| inner.poll_idle(ticket, cx)).await;
+
+        inner.idle_fired() - before
+    }
 }
 
 cfg_rt! {
@@ -13,12 +13,16 @@ pub(crate) use self::handle::Handle;
 
 use crate::loom::sync::atomic::{AtomicU64, AtomicUsize};
 use crate::park::{Park, Unpark};
-use crate::time::{error::Error, wheel};
+use crate::time::{
+    error::{ClockError, Error},
+    wheel,
+};
 use crate::time::{Clock, Duration, Instant};
 
 use std::sync::atomic::Ordering::{Acquire, Relaxed, Release, SeqCst};
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 use std::usize;
 use std::{cmp, fmt};
 
@@ -77,7 +81,6 @@ use std::{cmp, fmt};
 /// [sleep]: crate::time::Sleep
 /// [timeout]: crate::time::Timeout
 /// [interval]: crate::time::Interval
-#[derive(Debug)]
 pub(crate) struct Driver<T: Park> {
     /// Shared state
     inner: Arc<Inner>,
@@ -91,31 +94,126 @@ pub(crate) struct Driver<T: Park> {
     /// Source of "now" instances
     clock: Clock,
 
+    /// Key of the driver's registration with `clock`.
+    clock_key: u64,
+
+    /// Called each time a timer is fired.
+    on_fire: Option<OnFire>,
+
+    /// Which timers a pause of the clock freezes.
+    pause_scope: PauseScope,
+
+    /// The clock's pause count when the driver last looked at it.
+    pauses_seen: u64,
+
+    /// Timers running on real time, with the real instant at which they fire.
+    /// These are the timers whose deadline is a real instant, and, under
+    /// `PauseScope::FutureTimers`, those handed over to real time by a pause.
+    running: Vec<(std::time::Instant, Arc<Entry>)>,
+
     /// True if the driver is being shutdown
     is_shutdown: bool,
 }
 
+/// Callback invoked by the driver each time it fires a timer, with the
+/// timer's deadline and the number of timers left in the wheel.
+pub(crate) type OnFire = Arc<dyn Fn(Instant, usize) + Send + Sync>;
+
+/// Which timers are frozen when the clock of a runtime is paused, set with
+/// [`Builder::pause_scope`].
+///
+/// [`Builder::pause_scope`]: crate::runtime::Builder::pause_scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseScope {
+    /// Every timer is frozen while the clock is paused. This is the default.
+    AllTimers,
+
+    /// Only the timers registered while the clock is paused are frozen.
+    ///
+    /// A timer that the time driver has registered when the clock is paused
+    /// keeps counting down in real time, at the pace the clock was running
+    /// at, and fires once the logical time it had left has elapsed in real
+    /// time. It stays on real time until it fires, whether the clock is
+    /// resumed or advanced in between, much like a [`Sleep`] on which
+    /// [`Sleep::ignore_pause`] was called. Resetting it registers it anew.
+    ///
+    /// [`Sleep`]: crate::time::Sleep
+    /// [`Sleep::ignore_pause`]: crate::time::Sleep::ignore_pause
+    FutureTimers,
+}
+
+impl Default for PauseScope {
+    fn default() -> PauseScope {
+        PauseScope::AllTimers
+    }
+}
+
 /// Timer state shared between `Driver`, `Handle`, and `Registration`.
 pub(crate) struct Inner {
     /// The instant at which the timer started running.
     start: Instant,
 
+    /// The real instant at which the timer started running, from which the
+    /// deadlines of real-time entries are measured.
+    real_start: std::time::Instant,
+
     /// The last published timer `elapsed` value.
     elapsed: AtomicU64,
 
     /// Number of active timeouts
     num: AtomicUsize,
 
+    /// Earliest deadline of the registered timers, in milliseconds since
+    /// `start`, or `NO_WAKE` if there is none.
+    next_wake: AtomicU64,
+
+    /// Registration order to hand out to the next timer registered.
+    next_seq: AtomicU64,
+
+    /// Number of timers in the wheel, as last published by the driver.
+    armed: AtomicUsize,
+
     /// Head of the "process" linked list.
     process: AtomicStack,
 
     /// Unparks the timer thread.
     unpark: Box<dyn Unpark>,
+
+    /// Single steps of a paused clock requested through a `Handle`.
+    steps: Mutex<Steps>,
+}
+
+/// Tracks requests to advance a paused clock to the next timer deadline.
+#[derive(Debug, Default)]
+struct Steps {
+    /// Number of steps ever requested.
+    requested: u64,
+
+    /// Number of steps the driver has performed.
+    completed: u64,
+
+    /// Number of timers fired by the steps performed so far.
+    fired: u64,
+
+    /// Number of idle points ever requested.
+    idle_requested: u64,
+
+    /// Number of idle points the driver has reached.
+    idle_completed: u64,
+
+    /// Number of timers fired at the idle points reached so far.
+    idle_fired: u64,
+
+    /// Tasks waiting for their step to be performed, or for an idle point.
+    waiters: Vec<Waker>,
 }
 
 /// Maximum number of timeouts the system can handle concurrently.
 const MAX_TIMEOUTS: usize = usize::MAX >> 1;
 
+/// Value of `Inner::next_wake` when no timer is registered.
+const NO_WAKE: u64 = u64::MAX;
+
 // ===== impl Driver =====
 
 impl<T> Driver<T>
@@ -128,16 +226,34 @@ where
     /// Specifying the source of time is useful when testing.
     pub(crate) fn new(park: T, clock: Clock) -> Driver<T> {
         let unpark = Box::new(park.unpark());
+        let clock_key = clock.register_driver(Box::new(park.unpark()));
 
         Driver {
             inner: Arc::new(Inner::new(clock.now(), unpark)),
             wheel: wheel::Wheel::new(),
             park,
+            pauses_seen: clock.pause_count(),
             clock,
+            clock_key,
+            on_fire: None,
+            pause_scope: PauseScope::AllTimers,
+            running: Vec::new(),
             is_shutdown: false,
         }
     }
 
+    /// Sets the callback to invoke each time a timer is fired.
+    pub(crate) fn with_on_fire(mut self, on_fire: Option<OnFire>) -> Driver<T> {
+        self.on_fire = on_fire;
+        self
+    }
+
+    /// Sets which timers a pause of the clock freezes.
+    pub(crate) fn with_pause_scope(mut self, pause_scope: PauseScope) -> Driver<T> {
+        self.pause_scope = pause_scope;
+        self
+    }
+
     /// Returns a handle to the timer.
     ///
     /// The `Handle` is how `Sleep` instances are created. The `Sleep` instances
@@ -153,25 +269,217 @@ where
         self.inner.start + Duration::from_millis(when)
     }
 
-    /// Runs timer related logic
-    fn process(&mut self) {
+    /// Runs timer related logic, returning the number of timers fired.
+    fn process(&mut self) -> u64 {
         let now = crate::time::ms(
-            self.clock.now() - self.inner.start,
+            self.clock.now().saturating_duration_since(self.inner.start),
             crate::time::Round::Down,
         );
 
+        let mut fired = 0;
+        let mut due = Vec::new();
+
         while let Some(entry) = self.wheel.poll(now) {
+            due.push(entry);
+        }
+
+        // The wheel hands out the entries of a slot in no meaningful order.
+        // Fire timers sharing a deadline in the order they were registered,
+        // so that advancing a paused clock wakes tasks reproducibly.
+        due.sort_by_key(|entry| (entry.when_internal(), entry.seq()));
+
+        let mut left = due.len();
+
+        for entry in due {
             let when = entry.when_internal().expect("invalid internal entry state");
+            left -= 1;
 
             // Fire the entry
             entry.fire(when);
+            fired += 1;
+
+            // The wheel is owned by the driver rather than locked, so user
+            // code can run here without blocking the registration of timers.
+            if let Some(on_fire) = &self.on_fire {
+                on_fire(self.expiration_instant(when), self.wheel.len() + left);
+            }
 
             // Track that the entry has been fired
             entry.set_when_internal(None);
         }
 
+        fired += self.fire_running();
+
         // Update the elapsed cache
         self.inner.elapsed.store(self.wheel.elapsed(), SeqCst);
+        self.publish_wheel_state();
+
+        fired
+    }
+
+    /// Fires the timers running on real time whose real deadline has been
+    /// reached, returning the number of timers fired.
+    fn fire_running(&mut self) -> u64 {
+        if self.running.is_empty() {
+            return 0;
+        }
+
+        let now = std::time::Instant::now();
+        let mut fired = 0;
+        let mut i = 0;
+
+        while i < self.running.len() {
+            if self.running[i].0 > now {
+                i += 1;
+                continue;
+            }
+
+            let (_, entry) = self.running.swap_remove(i);
+            let when = entry.when_internal().expect("invalid internal entry state");
+
+            entry.fire(when);
+            fired += 1;
+
+            if let Some(on_fire) = &self.on_fire {
+                let deadline = if entry.is_real() {
+                    Instant::from_std(self.inner.real_instant(when))
+                } else {
+                    self.expiration_instant(when)
+                };
+
+                on_fire(deadline, self.wheel.len() + self.running.len());
+            }
+
+            entry.set_when_internal(None);
+        }
+
+        fired
+    }
+
+    /// Hands the timers in the wheel over to real time if the clock was
+    /// paused since the driver last looked, under `PauseScope::FutureTimers`.
+    ///
+    /// Called before processing the queue, so that timers registered since
+    /// the pause stay frozen.
+    fn release_armed_timers(&mut self) {
+        if self.pause_scope != PauseScope::FutureTimers {
+            return;
+        }
+
+        let pauses = self.clock.pause_count();
+
+        if pauses == self.pauses_seen {
+            return;
+        }
+
+        self.pauses_seen = pauses;
+
+        // The driver is unparked by every pause, but a pause can still end
+        // before it gets to look. Its timers then simply carry on.
+        let since = match self.clock.paused_since() {
+            Some(since) => since,
+            None => return,
+        };
+
+        let now = self.clock.now();
+
+        for entry in self.wheel.drain() {
+            let when = entry.when_internal().expect("invalid internal entry state");
+            let left = self.expiration_instant(when).saturating_duration_since(now);
+
+            match self.clock.real_duration(left) {
+                Some(left) => self.running.push((since + left, entry)),
+                // A clock scaled to a standstill never lets the timer run out.
+                None => self.add_entry(entry, when),
+            }
+        }
+
+        self.publish_wheel_state();
+    }
+
+    /// Returns the real time left until the next timer running on real time
+    /// fires, if any.
+    fn running_timeout(&self) -> Option<Duration> {
+        let now = std::time::Instant::now();
+
+        self.running
+            .iter()
+            .map(|(deadline, _)| deadline.saturating_duration_since(now))
+            .min()
+    }
+
+    /// Publishes the earliest deadline of the wheel, and the number of
+    /// timers armed, for `Handle`s to read.
+    fn publish_wheel_state(&self) {
+        let when = self.wheel.poll_at().unwrap_or(NO_WAKE);
+        self.inner.next_wake.store(when, SeqCst);
+        self.inner
+            .armed
+            .store(self.wheel.len() + self.running.len(), SeqCst);
+    }
+
+    /// Performs one requested step, if any, returning `true` if it did.
+    ///
+    /// A paused clock is advanced to the deadline of the next registered
+    /// timer, which is then fired. A clock that was resumed since the step
+    /// was requested is left alone.
+    fn step(&mut self) -> bool {
+        if !self.inner.has_pending_step() {
+            return false;
+        }
+
+        self.advance_to_next_timer();
+
+        let fired = self.process();
+        self.inner.complete_steps(1, fired);
+
+        true
+    }
+
+    /// Reaches the requested idle points, if any, returning `true` if it did.
+    ///
+    /// Only called when the scheduler parks for lack of ready tasks. Timers
+    /// that are due are fired, after advancing a paused clock to the
+    /// deadline of the next registered timer. A clock that was resumed since
+    /// the idle point was requested is left alone.
+    fn idle(&mut self) -> bool {
+        if !self.inner.has_pending_idle() {
+            return false;
+        }
+
+        self.advance_to_next_timer();
+
+        let fired = self.process();
+        self.inner.complete_idle(fired);
+
+        true
+    }
+
+    /// Advances a paused clock to the deadline of the next registered
+    /// timer. A running clock is left alone.
+    fn advance_to_next_timer(&mut self) {
+        if self.clock.is_paused() {
+            if let Some(when) = self.wheel.poll_at() {
+                let now = self.clock.now();
+                let deadline = self.expiration_instant(when);
+
+                if deadline > now {
+                    self.advance_if_paused(deadline - now);
+                }
+            }
+        }
+    }
+
+    /// Advances the clock by `duration` unless it is no longer paused.
+    ///
+    /// Another thread may resume the clock at any time, including between
+    /// checking that it is paused and advancing it, in which case it is left
+    /// alone.
+    fn advance_if_paused(&self, duration: Duration) {
+        match self.clock.try_advance(duration) {
+            Ok(()) | Err(ClockError::NotPaused) => {}
+            Err(err) => panic!("advancing the clock failed: {}", err),
+        }
     }
 
     /// Processes the entry queue
@@ -197,10 +505,21 @@ where
                 }
             }
         }
+
+        self.publish_wheel_state();
     }
 
     fn clear_entry(&mut self, entry: &Arc<Entry>) {
-        self.wheel.remove(entry);
+        let running = self
+            .running
+            .iter()
+            .position(|(_, running)| Arc::ptr_eq(running, entry));
+
+        match running {
+            Some(i) => drop(self.running.swap_remove(i)),
+            None => self.wheel.remove(entry),
+        }
+
         entry.set_when_internal(None);
     }
 
@@ -210,6 +529,14 @@ where
 
         entry.set_when_internal(Some(when));
 
+        // The wheel follows the clock, so real-time entries are kept aside and
+        // fired by `fire_running` once their deadline has passed.
+        if entry.is_real() {
+            let deadline = self.inner.real_instant(when);
+            self.running.push((deadline, entry));
+            return;
+        }
+
         match self.wheel.insert(when, entry) {
             Ok(_) => {}
             Err((entry, InsertError::Elapsed)) => {
@@ -240,8 +567,19 @@ where
     }
 
     fn park(&mut self) -> Result<(), Self::Error> {
+        self.release_armed_timers();
         self.process_queue();
 
+        if self.step() || self.idle() {
+            return self.park.park_timeout(Duration::from_secs(0));
+        }
+
+        // Timers running on real time fire on time, however long the clock
+        // stays paused.
+        if let Some(timeout) = self.running_timeout() {
+            return self.park_timeout(timeout);
+        }
+
         match self.wheel.poll_at() {
             Some(when) => {
 
@@ -254,14 +592,21 @@ where
                     if self.clock.is_paused() {
                         if Clock::is_test() {
                             self.park.park_timeout(Duration::from_secs(0))?;
-                            self.clock.advance(dur);
+                            self.advance_if_paused(dur);
                         }
                         else {
-                            self.clock.wait_for_resume();
-                            return self.park();
+                            // Logical time is frozen, so the deadline cannot
+                            // be reached until the clock is resumed or
+                            // advanced. Both unpark the driver.
+                            self.park.park()?;
                         }
                     } else {
-                        self.park.park_timeout(dur)?;
+                        // A scaled clock may need more or less real time to
+                        // reach the deadline, or never reach it at all.
+                        match self.clock.real_duration(dur) {
+                            Some(dur) => self.park.park_timeout(dur)?,
+                            None => self.park.park()?,
+                        }
                     }
                 } else {
                     self.park.park_timeout(Duration::from_secs(0))?;
@@ -277,29 +622,43 @@ where
         Ok(())
     }
 
-    fn park_timeout(&mut self, duration: Duration) -> Result<(), Self::Error> {
+    fn park_timeout(&mut self, timeout: Duration) -> Result<(), Self::Error> {
+        self.release_armed_timers();
         self.process_queue();
 
+        if self.step() {
+            return self.park.park_timeout(Duration::from_secs(0));
+        }
+
+        let timeout = self
+            .running_timeout()
+            .map_or(timeout, |running| cmp::min(running, timeout));
+
         match self.wheel.poll_at() {
             Some(when) => {
                 let now = self.clock.now();
                 let deadline = self.expiration_instant(when);
 
                 if deadline > now {
-                    let duration = cmp::min(deadline - now, duration);
+                    let duration = cmp::min(deadline - now, timeout);
 
                     if self.clock.is_paused() {
                         if Clock::is_test() {
                             self.park.park_timeout(Duration::from_secs(0))?;
-                            self.clock.advance(duration);
+                            self.advance_if_paused(duration);
                         }
                         else {
-                            self.clock.wait_for_resume();
-                            // Park for the remainder of the duration?
-                            let remainder = duration.checked_sub(self.clock.now() - now);
-                            return self.park_timeout(remainder.unwrap_or_default());
+                            // Logical time is frozen, so only the caller's
+                            // timeout bounds how long to park. Resuming or
+                            // advancing the clock unparks the driver.
+                            self.park.park_timeout(timeout)?;
                         }
                     } else {
+                        let duration = self
+                            .clock
+                            .real_duration(deadline - now)
+                            .map_or(timeout, |duration| cmp::min(duration, timeout));
+
                         self.park.park_timeout(duration)?;
                     }
                 } else {
@@ -307,7 +666,7 @@ where
                 }
             }
             None => {
-                self.park.park_timeout(duration)?;
+                self.park.park_timeout(timeout)?;
             }
         }
 
@@ -334,6 +693,17 @@ where
             entry.error(Error::shutdown());
         }
 
+        for (_, entry) in self.running.drain(..) {
+            entry.error(Error::shutdown());
+        }
+
+        self.publish_wheel_state();
+
+        // No more steps can be performed, so release anyone waiting on one.
+        self.inner.complete_steps(u64::MAX, 0);
+        self.inner.complete_idle(0);
+
+        self.clock.unregister_driver(self.clock_key);
         self.park.shutdown();
 
         self.is_shutdown = true;
@@ -349,21 +719,202 @@ where
     }
 }
 
+impl<T> fmt::Debug for Driver<T>
+where
+    T: Park + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Driver")
+            .field("inner", &self.inner)
+            .field("wheel", &self.wheel)
+            .field("park", &self.park)
+            .field("clock", &self.clock)
+            .field("clock_key", &self.clock_key)
+            .field("on_fire", &self.on_fire.as_ref().map(|_| "..."))
+            .field("pause_scope", &self.pause_scope)
+            .field("pauses_seen", &self.pauses_seen)
+            .field("running", &self.running.len())
+            .field("is_shutdown", &self.is_shutdown)
+            .finish()
+    }
+}
+
 // ===== impl Inner =====
 
 impl Inner {
     fn new(start: Instant, unpark: Box<dyn Unpark>) -> Inner {
         Inner {
             num: AtomicUsize::new(0),
+            next_wake: AtomicU64::new(NO_WAKE),
+            armed: AtomicUsize::new(0),
+            next_seq: AtomicU64::new(0),
             elapsed: AtomicU64::new(0),
             process: AtomicStack::new(),
             start,
+            real_start: std::time::Instant::now(),
             unpark,
+            steps: Mutex::new(Steps::default()),
+        }
+    }
+
+    /// Requests a step of a paused clock, returning a ticket to poll for its
+    /// completion with `poll_step`.
+    fn request_step(&self) -> u64 {
+        let ticket = {
+            let mut steps = self.steps.lock().unwrap();
+            steps.requested += 1;
+            steps.requested
+        };
+
+        self.unpark.unpark();
+        ticket
+    }
+
+    fn poll_step(&self, ticket: u64, cx: &mut Context<'_>) -> Poll<()> {
+        let mut steps = self.steps.lock().unwrap();
+
+        if steps.completed >= ticket {
+            return Poll::Ready(());
+        }
+
+        if !steps.waiters.iter().any(|waker| waker.will_wake(cx.waker())) {
+            steps.waiters.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+
+    /// Requests that the driver signal the next time the scheduler runs out
+    /// of ready tasks, returning a ticket to poll with `poll_idle`.
+    fn request_idle(&self) -> u64 {
+        let ticket = {
+            let mut steps = self.steps.lock().unwrap();
+            steps.idle_requested += 1;
+            steps.idle_requested
+        };
+
+        // A worker parked on the driver is idle already.
+        self.unpark.unpark();
+        ticket
+    }
+
+    fn poll_idle(&self, ticket: u64, cx: &mut Context<'_>) -> Poll<()> {
+        let mut steps = self.steps.lock().unwrap();
+
+        if steps.idle_completed >= ticket {
+            return Poll::Ready(());
+        }
+
+        if !steps.waiters.iter().any(|waker| waker.will_wake(cx.waker())) {
+            steps.waiters.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+
+    /// Returns the number of timers fired at the idle points reached so far.
+    fn idle_fired(&self) -> u64 {
+        self.steps.lock().unwrap().idle_fired
+    }
+
+    fn has_pending_idle(&self) -> bool {
+        let steps = self.steps.lock().unwrap();
+        steps.idle_completed < steps.idle_requested
+    }
+
+    /// Marks every pending idle point as reached, having fired `fired`
+    /// timers, and wakes the waiters.
+    fn complete_idle(&self, fired: u64) {
+        let waiters = {
+            let mut steps = self.steps.lock().unwrap();
+            steps.idle_completed = steps.idle_requested;
+            steps.idle_fired += fired;
+            std::mem::take(&mut steps.waiters)
+        };
+
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+
+    /// Returns the number of timers fired by the steps performed so far.
+    fn steps_fired(&self) -> u64 {
+        self.steps.lock().unwrap().fired
+    }
+
+    fn has_pending_step(&self) -> bool {
+        let steps = self.steps.lock().unwrap();
+        steps.completed < steps.requested
+    }
+
+    /// Marks up to `n` pending steps as performed, having fired `fired`
+    /// timers, and wakes their waiters.
+    fn complete_steps(&self, n: u64, fired: u64) {
+        let waiters = {
+            let mut steps = self.steps.lock().unwrap();
+            let pending = steps.requested - steps.completed;
+            steps.completed += cmp::min(n, pending);
+            steps.fired += fired;
+            std::mem::take(&mut steps.waiters)
+        };
+
+        for waker in waiters {
+            waker.wake();
         }
     }
 
-    fn elapsed(&self) -> u64 {
-        self.elapsed.load(SeqCst)
+    /// Returns the time elapsed since the timer started, in milliseconds, on
+    /// the clock or in real time.
+    ///
+    /// Real time is rounded up, like real deadlines, so that an entry created
+    /// with a deadline that has passed is elapsed right away.
+    fn elapsed(&self, real: bool) -> u64 {
+        if real {
+            let elapsed = std::time::Instant::now().saturating_duration_since(self.real_start);
+            crate::time::ms(elapsed, crate::time::Round::Up)
+        } else {
+            self.elapsed.load(SeqCst)
+        }
+    }
+
+    /// Converts the deadline of a real-time entry to a real instant.
+    fn real_instant(&self, when: u64) -> std::time::Instant {
+        self.real_start + Duration::from_millis(when)
+    }
+
+    /// Returns the earliest deadline of the registered timers.
+    ///
+    /// This is the deadline published by the driver the last time it
+    /// processed its timers, lowered by any timer registered since.
+    fn next_wake(&self) -> Option<Instant> {
+        match self.next_wake.load(SeqCst) {
+            NO_WAKE => None,
+            when => Some(self.start + Duration::from_millis(when)),
+        }
+    }
+
+    /// Returns the number of timers in the wheel.
+    ///
+    /// Unlike the earliest deadline, this is only updated by the driver, so
+    /// timers registered or cancelled since it last processed its queue are
+    /// not accounted for.
+    fn armed(&self) -> usize {
+        self.armed.load(SeqCst)
+    }
+
+    /// Lowers the published earliest deadline to `when` if it is later.
+    fn lower_next_wake(&self, when: u64) {
+        let mut curr = self.next_wake.load(SeqCst);
+
+        while when < curr {
+            let actual = self.next_wake.compare_and_swap(curr, when, SeqCst);
+
+            if actual == curr {
+                break;
+            }
+
+            curr = actual;
+        }
     }
 
     #[cfg(all(test, loom))]
@@ -371,6 +922,11 @@ impl Inner {
         self.num.load(ordering)
     }
 
+    /// Hands out the registration order of a timer being registered.
+    fn next_seq(&self) -> u64 {
+        self.next_seq.fetch_add(1, SeqCst)
+    }
+
     /// Increments the number of active timeouts
     fn increment(&self) -> Result<(), Error> {
         let mut curr = self.num.load(Relaxed);
@@ -405,10 +961,23 @@ impl Inner {
             self.unpark.unpark();
         }
 
+        // The driver only learns of the entry once it processes the queue, so
+        // account for it right away. Real-time entries are not on the clock.
+        if let Some(when) = entry.load_state().filter(|_| !entry.is_real()) {
+            self.lower_next_wake(when);
+        }
+
         Ok(())
     }
 
-    fn normalize_deadline(&self, deadline: Instant) -> u64 {
+    fn normalize_deadline(&self, deadline: Instant, real: bool) -> u64 {
+        if real {
+            let deadline = deadline
+                .into_std()
+                .saturating_duration_since(self.real_start);
+            return crate::time::ms(deadline, crate::time::Round::Up);
+        }
+
         if deadline < self.start {
             return 0;
         }
@@ -38,6 +38,67 @@ enum Kind {
 #[derive(Debug, PartialEq)]
 pub struct Elapsed(());
 
+/// Error returned when the runtime's clock cannot perform a pause related
+/// operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClockError {
+    /// The runtime was not built with a pausable clock.
+    NotPausable,
+
+    /// The clock is already paused.
+    AlreadyPaused,
+
+    /// The clock is not paused.
+    NotPaused,
+
+    /// The clock backend does not support the operation. The `test-util`
+    /// clock cannot hold off a resume while a closure runs, for example.
+    Unsupported,
+
+    /// The requested instant is earlier than the clock's current instant.
+    InPast,
+
+    /// The clock was paused, resumed or advanced from within its own
+    /// `on_time_warp` callback, under [`PanicPolicy::Error`].
+    ///
+    /// [`PanicPolicy::Error`]: PanicPolicy::Error
+    InWarpCallback,
+
+    /// Advancing the clock by the requested duration would take it past
+    /// the latest instant it can represent.
+    Overflow,
+}
+
+/// How the fallible operations of a runtime's clock report misuse, set with
+/// [`Builder::clock_panic_policy`].
+///
+/// Some misuses of a clock are bugs in the calling code rather than states to
+/// recover from, and panic by default: pausing, resuming or advancing the
+/// clock from within an `on_time_warp` callback is one. Library authors that
+/// embed a runtime may prefer to surface those as errors instead.
+///
+/// [`Builder::clock_panic_policy`]: crate::runtime::Builder::clock_panic_policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Misuses panic, even through the methods that return a
+    /// [`ClockError`]. This is the default.
+    Panic,
+
+    /// The methods that return a [`ClockError`] return an error on misuse,
+    /// such as [`ClockError::InWarpCallback`]. Methods that cannot fail, like
+    /// [`Handle::advance_time`], still panic.
+    ///
+    /// [`Handle::advance_time`]: crate::runtime::Handle::advance_time
+    Error,
+}
+
+impl Default for PanicPolicy {
+    fn default() -> PanicPolicy {
+        PanicPolicy::Panic
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum InsertError {
     Elapsed,
@@ -126,3 +187,24 @@ impl From<Elapsed> for std::io::Error {
         std::io::ErrorKind::TimedOut.into()
     }
 }
+
+// ===== impl ClockError =====
+
+impl fmt::Display for ClockError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let descr = match self {
+            ClockError::NotPausable => "the runtime's clock is not pausable",
+            ClockError::AlreadyPaused => "the runtime's clock is already paused",
+            ClockError::NotPaused => "the runtime's clock is not paused",
+            ClockError::Unsupported => "the runtime's clock does not support this operation",
+            ClockError::InPast => "the instant is earlier than the runtime's clock",
+            ClockError::InWarpCallback => {
+                "the runtime's clock cannot change from within an `on_time_warp` callback"
+            }
+            ClockError::Overflow => "advancing the runtime's clock would overflow it",
+        };
+        write!(fmt, "{}", descr)
+    }
+}
+
+impl std::error::Error for ClockError {}
@@ -0,0 +1,66 @@
+use crate::time::driver::Handle;
+
+/// Waits for the runtime to settle: for no task to be ready to run and no
+/// timer to be due.
+///
+/// The returned future proceeds in rounds. Each round waits until the
+/// scheduler runs out of ready tasks and parks, letting every other task
+/// run first. At that point, the time driver fires the timers that are due,
+/// having first advanced the clock to the deadline of the next timer if it
+/// is paused. If any timer fired, the tasks it woke run and another round
+/// starts. The future completes at the end of the first round that fires
+/// no timer, that is once no task is ready and no timer has expired. On a
+/// paused clock, this means that no timer is left at all.
+///
+/// This is the cooperative counterpart of [`Handle::drain_timers`]: rather
+/// than firing timers on demand, it waits for the runtime to run out of
+/// work before moving a paused clock, which makes it convenient to let
+/// everything settle at chosen points of a test. It works on running and
+/// ordinary clocks as well, which are never advanced.
+///
+/// The future never completes while a task keeps itself ready, for
+/// instance by yielding in a loop, or while a task keeps scheduling new
+/// timers on a paused clock, as an [`Interval`] does. On a multi-thread
+/// runtime, the runtime counts as idle once the worker driving the timers
+/// runs out of tasks, even if other workers are still busy, so this is
+/// mostly useful on a current-thread runtime.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime or if the runtime
+/// was built without the time driver.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time::{self, Duration, Instant};
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .pausable_time(true, Duration::from_secs(0))
+///     .build()
+///     .unwrap();
+///
+/// rt.block_on(async {
+///     let start = Instant::now();
+///     let done = tokio::spawn(async {
+///         time::sleep(Duration::from_secs(1)).await;
+///         tokio::task::yield_now().await;
+///         time::sleep(Duration::from_secs(2)).await;
+///     });
+///
+///     time::run_until_idle().await;
+///
+///     assert!(done.await.is_ok());
+///     assert_eq!(start + Duration::from_secs(3), Instant::now());
+/// });
+/// ```
+///
+/// [`Handle::drain_timers`]: crate::runtime::Handle::drain_timers
+/// [`Interval`]: crate::time::Interval
+pub async fn run_until_idle() {
+    let handle = Handle::current();
+
+    while handle.idle().await > 0 {}
+}
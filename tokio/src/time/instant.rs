@@ -29,7 +29,8 @@ use std::time::Duration;
 ///
 /// This type wraps the inner `std` variant and is used to align the Tokio
 /// clock for uses of `now()`. This can be useful for testing where you can
-/// take advantage of `time::pause()` and `time::advance()`.
+/// take advantage of `time::pause()` and `time::advance()`, and keeps
+/// instants on the same timeline as a runtime built with a pausable clock.
 #[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct Instant {
     std: std::time::Instant,
@@ -59,13 +60,19 @@ impl Instant {
         self.std
     }
 
-    /// Returns the amount of time elapsed from another instant to this one.
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
     ///
-    /// # Panics
+    /// Instants taken from a pausable clock lag behind the wall clock by the
+    /// time spent paused, and a clock restored from a saved state starts over
+    /// from its saved position. Mixing such instants with instants captured
+    /// elsewhere can make `earlier` later than `self`, so unlike
+    /// [`std::time::Instant::duration_since`] this does not panic in that
+    /// case. Use [`checked_duration_since`] to detect it.
     ///
-    /// This function will panic if `earlier` is later than `self`.
+    /// [`checked_duration_since`]: Instant::checked_duration_since
     pub fn duration_since(&self, earlier: Instant) -> Duration {
-        self.std.duration_since(earlier.std)
+        self.saturating_duration_since(earlier)
     }
 
     /// Returns the amount of time elapsed from another instant to this one, or
@@ -110,13 +117,18 @@ impl Instant {
         self.std.saturating_duration_since(earlier.std)
     }
 
-    /// Returns the amount of time elapsed since this instant was created.
+    /// Returns the amount of time elapsed since this instant was created, or
+    /// zero duration if the current time is earlier than this instant.
     ///
-    /// # Panics
+    /// The current time is read from the runtime's clock, like
+    /// [`Instant::now`], so the elapsed time does not grow while the clock is
+    /// paused.
     ///
-    /// This function may panic if the current time is earlier than this
-    /// instant, which is something that can happen if an `Instant` is
-    /// produced synthetically.
+    /// The current time can be earlier than an `Instant` produced
+    /// synthetically, or captured from the wall clock or from another
+    /// runtime's clock. See [`duration_since`] for details.
+    ///
+    /// [`duration_since`]: Instant::duration_since
     ///
     /// # Examples
     ///
@@ -180,7 +192,7 @@ impl ops::Sub for Instant {
     type Output = Duration;
 
     fn sub(self, rhs: Instant) -> Duration {
-        self.std - rhs.std
+        self.saturating_duration_since(rhs)
     }
 }
 
@@ -204,16 +216,6 @@ impl fmt::Debug for Instant {
     }
 }
 
-#[cfg(not(feature = "test-util"))]
-mod variant {
-    use super::Instant;
-
-    pub(super) fn now() -> Instant {
-        Instant::from_std(std::time::Instant::now())
-    }
-}
-
-#[cfg(feature = "test-util")]
 mod variant {
     use super::Instant;
 
@@ -80,6 +80,18 @@ pub fn interval(period: Duration) -> Interval {
 ///
 /// This function panics if `period` is zero.
 ///
+/// # Pausing
+///
+/// `start` and every following tick are instants of the runtime's clock, and
+/// whether a tick is due is checked against that clock's `now()`. On a
+/// runtime with a pausable clock, the whole schedule therefore stands still
+/// while the clock is paused: a tick that had 300ms left when the clock
+/// paused still has 300ms left once it resumes, and the ticks keep landing
+/// exactly on `start + n * period`.
+///
+/// As with [`sleep_until`], `start` should be derived from [`Instant::now`]
+/// called from within the runtime.
+///
 /// # Examples
 ///
 /// ```
@@ -97,17 +109,89 @@ pub fn interval(period: Duration) -> Interval {
 ///     // approximately 70ms have elapsed.
 /// }
 /// ```
+///
+/// [`sleep_until`]: crate::time::sleep_until
+/// [`Instant::now`]: crate::time::Instant::now
 pub fn interval_at(start: Instant, period: Duration) -> Interval {
     assert!(period > Duration::new(0, 0), "`period` must be non-zero.");
 
     Interval {
         delay: sleep_until(start),
         period,
+        missed_tick_behavior: Default::default(),
+    }
+}
+
+/// Defines the behavior of an [`Interval`] when it misses a tick.
+///
+/// Sometimes, an [`Interval`]'s tick is missed. For example, the task polling
+/// the interval may be busy for longer than the period, or a paused clock may
+/// be advanced past several ticks at once. Logical time does not elapse while
+/// a pausable clock is paused, so a pause on its own never causes a missed
+/// tick.
+///
+/// By default, when a tick is missed, [`Interval`] fires ticks as quickly as it
+/// can until it is caught up with where it should be (see [`Burst`]).
+///
+/// [`Burst`]: MissedTickBehavior::Burst
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedTickBehavior {
+    /// Ticks as fast as possible until caught up.
+    ///
+    /// After being caught up, the interval keeps ticking on its original
+    /// schedule, as if no tick had been missed. With a period of 50ms starting
+    /// at 0ms, if the ticks at 50ms and 100ms are missed because time jumps to
+    /// 120ms, the interval yields the 50ms and 100ms ticks immediately and then
+    /// the 150ms tick at 150ms.
+    Burst,
+
+    /// Ticks one period after the missed tick is observed.
+    ///
+    /// The schedule is shifted so that ticks keep being spaced by the period
+    /// from the point where the interval caught up. With a period of 50ms
+    /// starting at 0ms, if time jumps to 120ms, the interval yields the 50ms
+    /// tick immediately and then ticks at 170ms, 220ms, and so on.
+    Delay,
+
+    /// Skips missed ticks and ticks on the next multiple of the period.
+    ///
+    /// Exactly one tick is yielded immediately for all of the missed ticks,
+    /// then the interval realigns with its original schedule. With a period
+    /// of 50ms starting at 0ms, if time jumps to 120ms, the interval yields
+    /// the 50ms tick immediately and then ticks at 150ms, 200ms, and so on.
+    Skip,
+}
+
+impl MissedTickBehavior {
+    /// Returns the deadline of the next tick, given the deadline of the tick
+    /// that was missed and the instant it was observed at.
+    fn next_timeout(&self, timeout: Instant, now: Instant, period: Duration) -> Instant {
+        match self {
+            MissedTickBehavior::Burst => timeout + period,
+            MissedTickBehavior::Delay => now + period,
+            MissedTickBehavior::Skip => {
+                let behind = now.saturating_duration_since(timeout).as_nanos();
+                let into_period = (behind % period.as_nanos()) as u64;
+
+                now + period - Duration::from_nanos(into_period)
+            }
+        }
+    }
+}
+
+impl Default for MissedTickBehavior {
+    /// Returns [`MissedTickBehavior::Burst`].
+    fn default() -> Self {
+        MissedTickBehavior::Burst
     }
 }
 
 /// Stream returned by [`interval`](interval) and [`interval_at`](interval_at).
 ///
+/// Ticks are scheduled against the runtime's clock. On a runtime built with a
+/// pausable clock, time spent paused does not count towards the period, so
+/// resuming after a long pause does not produce a burst of missed ticks.
+///
 /// This type only implements the [`Stream`] trait if the "stream" feature is
 /// enabled.
 ///
@@ -119,6 +203,9 @@ pub struct Interval {
 
     /// The duration between values yielded by `Interval`.
     period: Duration,
+
+    /// The strategy `Interval` should use when a tick is missed.
+    missed_tick_behavior: MissedTickBehavior,
 }
 
 impl Interval {
@@ -126,16 +213,36 @@ impl Interval {
         // Wait for the delay to be done
         ready!(Pin::new(&mut self.delay).poll(cx));
 
-        // Get the `now` by looking at the `delay` deadline
-        let now = self.delay.deadline();
+        // Get the instant the tick was scheduled for by looking at the
+        // `delay` deadline
+        let timeout = self.delay.deadline();
+        let now = Instant::now();
+
+        // The timer driver only has millisecond resolution, so allow a small
+        // amount of slack before considering the tick missed.
+        let next = if now > timeout + Duration::from_millis(5) {
+            self.missed_tick_behavior
+                .next_timeout(timeout, now, self.period)
+        } else {
+            // The next interval value is `duration` after the one that just
+            // yielded.
+            timeout + self.period
+        };
 
-        // The next interval value is `duration` after the one that just
-        // yielded.
-        let next = now + self.period;
         self.delay.reset(next);
 
-        // Return the current instant
-        Poll::Ready(now)
+        // Return the instant the tick was scheduled for
+        Poll::Ready(timeout)
+    }
+
+    /// Returns the [`MissedTickBehavior`] strategy currently being used.
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Sets the [`MissedTickBehavior`] strategy that should be used.
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
     }
 
     /// Completes when the next instant in the interval has been reached.
@@ -0,0 +1,222 @@
+use crate::time::{Clock, Duration, Instant};
+
+use pin_project_lite::pin_project;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+thread_local! {
+    /// Clock installed by the `WithClock` future currently being polled on
+    /// this thread, if any.
+    static OVERRIDE: RefCell<Option<Clock>> = RefCell::new(None)
+}
+
+/// A pausable clock that is owned independently of any runtime.
+///
+/// A `LocalClock` can be used in two ways:
+///
+/// * Handed to [`Builder::clock`], it becomes the clock of the runtimes built
+///   with it. A test framework can create one clock and share it between
+///   several runtimes, so that pausing it freezes all of them at once.
+///
+/// * Futures run with [`with_clock`] observe this clock instead of their
+///   runtime's: [`Instant::now`] reports its time, the pause and resume
+///   functions of [`tokio::time`] act on it, and sleeps, intervals and
+///   timeouts created inside them measure their deadlines against it. This
+///   gives several groups of tasks sharing one runtime their own,
+///   independently pausable timelines.
+///
+/// Cloning a `LocalClock` returns another handle to the same clock. The
+/// clock is thread-safe and can be paused and resumed from any thread.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, Duration, LocalClock};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let clock = LocalClock::new(false);
+/// clock.pause();
+///
+/// time::with_clock(clock.clone(), async {
+///     assert!(time::is_paused());
+/// })
+/// .await;
+///
+/// clock.resume();
+///
+/// time::with_clock(clock, async {
+///     time::sleep(Duration::from_millis(10)).await;
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// [`Builder::clock`]: crate::runtime::Builder::clock
+/// [`Instant::now`]: crate::time::Instant::now
+/// [`tokio::time`]: crate::time
+#[derive(Debug, Clone)]
+pub struct LocalClock {
+    clock: Clock,
+}
+
+impl LocalClock {
+    /// Creates a new clock, paused if `start_paused` is `true`.
+    pub fn new(start_paused: bool) -> LocalClock {
+        LocalClock {
+            clock: Clock::new_pausable(start_paused, Duration::from_secs(0)),
+        }
+    }
+
+    /// Returns the current instant on this clock.
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Returns `true` if the clock is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.clock.is_paused()
+    }
+
+    /// Returns the logical time elapsed since the clock was created,
+    /// excluding the time it spent paused.
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed()
+    }
+
+    /// Pauses the clock, returning `true` if it was running.
+    ///
+    /// Pauses nest like those of the runtime's clock: each call must be
+    /// balanced by a call to [`resume`] before the clock runs again.
+    ///
+    /// [`resume`]: LocalClock::resume
+    pub fn pause(&self) -> bool {
+        self.clock.pause()
+    }
+
+    /// Balances one call to [`pause`], returning `true` if the clock is
+    /// running again as a result.
+    ///
+    /// [`pause`]: LocalClock::pause
+    pub fn resume(&self) -> bool {
+        self.clock.resume()
+    }
+
+    /// Returns `true` if `self` and `other` are handles to the same clock.
+    ///
+    /// This is analogous to [`Arc::ptr_eq`]: clones of a `LocalClock` are
+    /// equal, while two clocks created separately never are, even if they
+    /// currently report the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::LocalClock;
+    ///
+    /// let clock = LocalClock::new(true);
+    ///
+    /// assert!(clock.ptr_eq(&clock.clone()));
+    /// assert!(!clock.ptr_eq(&LocalClock::new(true)));
+    /// ```
+    ///
+    /// [`Arc::ptr_eq`]: std::sync::Arc::ptr_eq
+    pub fn ptr_eq(&self, other: &LocalClock) -> bool {
+        self.clock.ptr_eq(&other.clock)
+    }
+
+    pub(crate) fn as_clock(&self) -> &Clock {
+        &self.clock
+    }
+}
+
+/// Runs `future` against `clock` instead of the runtime's clock.
+///
+/// While `future` is polled, [`Instant::now`] and the clock functions of
+/// [`tokio::time`] use `clock`, and timers created by it measure their
+/// deadlines on `clock`. A sleep created under a paused clock does not
+/// complete until that clock is resumed and reaches its deadline. The
+/// runtime's clock is observed again as soon as `future` returns control,
+/// including between its polls, so the override is scoped to `future` alone.
+///
+/// Tasks spawned from within `future` do not inherit the override; wrap them
+/// in `with_clock` as well to place them on the same timeline. Timers still
+/// rely on the runtime's time driver, so pausing the runtime's clock also
+/// holds off timers running against a local clock.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, Duration, LocalClock};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let clock = LocalClock::new(true);
+///
+/// // The runtime's clock keeps running.
+/// assert!(!time::is_paused());
+///
+/// let now = time::with_clock(clock.clone(), async { time::Instant::now() }).await;
+/// assert_eq!(now, clock.now());
+/// # }
+/// ```
+///
+/// [`Instant::now`]: crate::time::Instant::now
+/// [`tokio::time`]: crate::time
+pub async fn with_clock<F: Future>(clock: LocalClock, future: F) -> F::Output {
+    WithClock {
+        clock: Some(clock.clock),
+        future,
+    }
+    .await
+}
+
+pin_project! {
+    /// Installs `clock` as the override around each poll of `future`.
+    struct WithClock<F> {
+        clock: Option<Clock>,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<F: Future> Future for WithClock<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        struct Guard<'a> {
+            slot: &'a mut Option<Clock>,
+            prev: Option<Clock>,
+        }
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                *self.slot = OVERRIDE.with(|c| c.replace(self.prev.take()));
+            }
+        }
+
+        let project = self.project();
+        let prev = OVERRIDE.with(|c| c.replace(project.clock.take()));
+
+        let _guard = Guard {
+            slot: project.clock,
+            prev,
+        };
+
+        project.future.poll(cx)
+    }
+}
+
+/// Returns the clock installed by `with_clock`, if any.
+pub(crate) fn current() -> Option<Clock> {
+    with_override(|clock| clock.cloned())
+}
+
+/// Calls `f` with the clock installed by `with_clock`, if any.
+pub(crate) fn with_override<F, R>(f: F) -> R
+where
+    F: FnOnce(Option<&Clock>) -> R,
+{
+    OVERRIDE.with(|clock| f(clock.borrow().as_ref()))
+}
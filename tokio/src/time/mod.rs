@@ -19,6 +19,16 @@
 //!
 //! These types must be used from within the context of the `Runtime`.
 //!
+//! # Pausing
+//!
+//! A runtime built with [`pausable_time`] has a clock that can be paused and
+//! resumed. The free functions of this module, such as [`is_paused`],
+//! [`try_pause`] and [`wait_for_resume`], act on the clock of the runtime
+//! they are called from and panic when called from outside of a runtime.
+//! To control a runtime's clock from any other thread, use the methods of a
+//! cloned [`Handle`], such as [`Handle::pause_time`] and
+//! [`Handle::resume_time`].
+//!
 //! # Examples
 //!
 //! Wait 100ms and print "100 ms have elapsed"
@@ -87,16 +97,55 @@
 //!
 //! [`sleep`]: crate::time::sleep()
 //! [`interval`]: crate::time::interval()
+//! [`pausable_time`]: crate::runtime::Builder::pausable_time
+//! [`Handle`]: crate::runtime::Handle
+//! [`Handle::pause_time`]: crate::runtime::Handle::pause_time
+//! [`Handle::resume_time`]: crate::runtime::Handle::resume_time
 
 mod clock;
-pub(crate) use self::clock::Clock;
+pub(crate) use self::clock::{Clock, WarpCallback, MAX_TIME_SCALE};
+pub use clock::{
+    assert_paused, assert_resumed, clock_snapshot, clock_state, duration_until, elapsed, frozen,
+    frozen_now, is_paused, is_paused_ordered, is_paused_relaxed, last_pause_real_instant,
+    logical_nanos, now_std, pause_generation, paused_duration, run_if_paused, run_if_resumed,
+    run_unpausable, run_unresumable, sleep_until_advance, system_now, try_advance, try_pause,
+    try_resume, wait_for_pause, wait_for_resume, wait_for_resume_timeout, ClockKind, ClockSnapshot,
+    ClockState,
+};
 #[cfg(feature = "test-util")]
 pub use clock::{advance, pause, resume};
+cfg_sync! {
+    pub use clock::resume_notify;
+}
+
+mod idle;
+pub use idle::run_until_idle;
+
+mod local_clock;
+pub use local_clock::{with_clock, LocalClock};
+
+mod on_pause;
+pub use on_pause::{on_pause, OnPause};
+
+mod pause_events;
+pub use pause_events::{pause_events, PauseEvent, PauseEvents};
+
+mod pause_guard;
+pub use pause_guard::PauseGuard;
+
+cfg_rt! {
+    mod pause_timer;
+    pub use pause_timer::PauseTimer;
+}
 
 mod sleep;
 pub use sleep::{sleep, sleep_until, Sleep};
 
+mod sleep_realtime;
+pub use sleep_realtime::{sleep_realtime, SleepRealtime};
+
 pub(crate) mod driver;
+pub use driver::PauseScope;
 
 pub mod error;
 
@@ -104,7 +153,10 @@ mod instant;
 pub use self::instant::Instant;
 
 mod interval;
-pub use interval::{interval, interval_at, Interval};
+pub use interval::{interval, interval_at, Interval, MissedTickBehavior};
+
+mod time_source;
+pub use time_source::{PausableTimeSource, SystemTimeSource, TimeSource};
 
 mod timeout;
 #[doc(inline)]
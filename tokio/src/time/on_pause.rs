@@ -0,0 +1,100 @@
+use crate::time::Clock;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Waits for the next time the runtime's clock is paused.
+///
+/// Unlike [`wait_for_pause`], the returned future does not complete if the
+/// clock is already paused when it is created: it only completes once the
+/// clock is paused again after that. This makes it a one-shot trigger that
+/// composes with `tokio::select!` to react to the next pause while doing
+/// other work.
+///
+/// The future is cheap to create. Dropping it withdraws its registration
+/// with the clock, so futures abandoned by `select!` do not accumulate.
+/// Runtimes that were not built with a pausable clock are never paused, so
+/// the future never completes for them.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time;
+///
+/// # async fn save_checkpoint() {}
+/// # async fn work() -> u32 { 0 }
+/// # async fn dox() -> Option<u32> {
+/// tokio::select! {
+///     _ = time::on_pause() => {
+///         save_checkpoint().await;
+///         None
+///     }
+///     r = work() => Some(r),
+/// }
+/// # }
+/// ```
+///
+/// [`wait_for_pause`]: crate::time::wait_for_pause
+pub fn on_pause() -> OnPause {
+    let clock = crate::time::clock::clock()
+        .expect("time cannot be observed from outside the Tokio runtime");
+
+    OnPause {
+        pauses: clock.pause_count(),
+        clock,
+        key: None,
+    }
+}
+
+/// Future returned by [`on_pause`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OnPause {
+    clock: Clock,
+
+    /// Number of pauses of the clock when the future was created.
+    pauses: u64,
+
+    /// Key of the waker registration with the clock, once registered.
+    key: Option<u64>,
+}
+
+impl OnPause {
+    fn has_paused(&self) -> bool {
+        self.clock.pause_count() > self.pauses
+    }
+}
+
+impl Future for OnPause {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let me = self.get_mut();
+
+        if me.has_paused() {
+            return Poll::Ready(());
+        }
+
+        me.clock.register_pause_waiter(&mut me.key, cx.waker());
+
+        // The clock may have been paused before the waker was registered.
+        if me.has_paused() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for OnPause {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.clock.unregister_pause_waiter(key);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::time::Clock;
+
+use std::task::{Context, Poll};
+
+/// A transition of the runtime's clock, yielded by [`PauseEvents`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseEvent {
+    /// The clock was paused.
+    Paused,
+
+    /// The clock was resumed.
+    Resumed,
+
+    /// The consumer fell behind and the given number of transitions were
+    /// dropped from the buffer before they could be observed.
+    Lagged(u64),
+}
+
+/// Stream of the runtime clock's pause and resume transitions.
+///
+/// Returned by [`pause_events`]. Only transitions that happen after the
+/// stream was created are yielded.
+///
+/// Transitions are buffered on the clock so that a slow consumer never holds
+/// up a pause or a resume. Once the buffer is full the oldest transitions are
+/// dropped and the consumer observes a [`PauseEvent::Lagged`] instead.
+///
+/// This type only implements the [`Stream`] trait if the "stream" feature is
+/// enabled.
+///
+/// [`Stream`]: trait@crate::stream::Stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct PauseEvents {
+    /// `None` if the runtime's clock is not pausable, in which case the
+    /// stream is empty.
+    clock: Option<Clock>,
+
+    /// Sequence number of the next transition to yield.
+    next: u64,
+
+    /// Key under which the consumer is registered with the clock, once it
+    /// has waited for a transition.
+    key: Option<u64>,
+}
+
+/// Returns a stream of the runtime clock's pause and resume transitions.
+///
+/// If the runtime was not built with a pausable clock, the stream is empty.
+///
+/// # Panics
+///
+/// Panics if called from outside of the Tokio runtime.
+pub fn pause_events() -> PauseEvents {
+    let clock = super::clock::clock()
+        .expect("time cannot be observed from outside the Tokio runtime");
+
+    if clock.pausable() {
+        let next = clock.pause_generation();
+
+        PauseEvents {
+            clock: Some(clock),
+            next,
+            key: None,
+        }
+    } else {
+        PauseEvents {
+            clock: None,
+            next: 0,
+            key: None,
+        }
+    }
+}
+
+impl PauseEvents {
+    /// Receives the next transition of the clock.
+    ///
+    /// Returns `None` if the runtime's clock is not pausable.
+    pub async fn recv(&mut self) -> Option<PauseEvent> {
+        crate::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<PauseEvent>> {
+        match &self.clock {
+            Some(clock) => clock
+                .poll_pause_event(&mut self.next, &mut self.key, cx)
+                .map(Some),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl Drop for PauseEvents {
+    fn drop(&mut self) {
+        if let (Some(clock), Some(key)) = (&self.clock, self.key) {
+            clock.remove_pause_waker(key);
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl crate::stream::Stream for PauseEvents {
+    type Item = PauseEvent;
+
+    fn poll_next(mut self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<PauseEvent>> {
+        self.poll_recv(cx)
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "rt"), allow(dead_code))]
+
+use crate::time::Clock;
+
+/// Guard that keeps the runtime's clock paused until it is dropped.
+///
+/// Returned by [`Handle::pause_scope`]. Dropping the guard balances the pause
+/// it took, including when the scope is left by an early return or a panic.
+///
+/// Pauses nest, so if the clock was already paused when the guard was created
+/// it stays paused after the guard is dropped.
+///
+/// [`Handle::pause_scope`]: crate::runtime::Handle::pause_scope
+#[derive(Debug)]
+#[must_use = "the clock is resumed as soon as the guard is dropped"]
+pub struct PauseGuard {
+    clock: Clock,
+}
+
+impl PauseGuard {
+    pub(crate) fn new(clock: Clock) -> PauseGuard {
+        clock.pause();
+
+        PauseGuard { clock }
+    }
+}
+
+impl Drop for PauseGuard {
+    fn drop(&mut self) {
+        self.clock.resume();
+    }
+}
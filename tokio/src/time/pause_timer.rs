@@ -0,0 +1,29 @@
+use crate::runtime::task::JoinHandle;
+
+/// Automatic resume of the runtime's clock scheduled by
+/// [`Handle::pause_for`].
+///
+/// The resume is driven by the runtime, on real time, so it happens even
+/// though the runtime's own timers are frozen. Dropping the `PauseTimer`
+/// leaves it scheduled; call [`cancel`] to withdraw it and keep the clock
+/// paused.
+///
+/// [`Handle::pause_for`]: crate::runtime::Handle::pause_for
+/// [`cancel`]: method@Self::cancel
+#[derive(Debug)]
+pub struct PauseTimer {
+    task: JoinHandle<()>,
+}
+
+impl PauseTimer {
+    pub(crate) fn new(task: JoinHandle<()>) -> PauseTimer {
+        PauseTimer { task }
+    }
+
+    /// Cancels the automatic resume, leaving the clock as it is.
+    ///
+    /// Does nothing if the resume already happened.
+    pub fn cancel(self) {
+        self.task.abort();
+    }
+}
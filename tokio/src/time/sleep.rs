@@ -1,7 +1,6 @@
-
 use crate::runtime::context::current;
 use crate::time::driver::{Entry, Handle};
-use crate::time::{error::Error, Duration, Instant};
+use crate::time::{error::Error, local_clock, Clock, Duration, Instant};
 
 use std::future::Future;
 use std::pin::Pin;
@@ -14,10 +13,26 @@ use std::task::{self, Poll};
 /// operates at millisecond granularity and should not be used for tasks that
 /// require high-resolution timers.
 ///
+/// # Pausing
+///
+/// `deadline` is interpreted on the runtime's clock. On a runtime with a
+/// pausable clock, that clock stops while paused, so a deadline computed
+/// before a pause still has the same logical time remaining after resuming.
+///
+/// Deadlines should be derived from [`Instant::now`] called from within the
+/// runtime, for example `Instant::now() + Duration::from_secs(1)`. Instants
+/// converted from the wall clock with [`Instant::from_std`] are not on the
+/// pausable timeline and will drift from it by the total time spent paused.
+///
+/// Within [`with_clock`], `deadline` is interpreted on the installed clock
+/// instead.
+///
 /// # Cancellation
 ///
 /// Canceling a sleep instance is done by dropping the returned future. No additional
 /// cleanup work is required.
+///
+/// [`with_clock`]: crate::time::with_clock
 pub fn sleep_until(deadline: Instant) -> Sleep {
     Sleep::new_timeout(deadline, Duration::from_millis(0))
 }
@@ -35,6 +50,13 @@ pub fn sleep_until(deadline: Instant) -> Sleep {
 ///
 /// The maximum duration for a sleep is 68719476734 milliseconds (approximately 2.2 years).
 ///
+/// # Pausing
+///
+/// The countdown starts from the clock's `now()` at the time `sleep` is
+/// called. If the clock is already paused, that is the instant at which it
+/// froze, so the full `duration` of logical time remains once it resumes,
+/// however long the pause lasted.
+///
 /// # Cancellation
 ///
 /// Canceling a sleep instance is done by dropping the returned future. No additional
@@ -56,7 +78,12 @@ pub fn sleep_until(deadline: Instant) -> Sleep {
 ///
 /// [`interval`]: crate::time::interval()
 pub fn sleep(duration: Duration) -> Sleep {
-    sleep_until(current().expect("No Runtime").now() + duration)
+    let now = match local_clock::current() {
+        Some(clock) => clock.now(),
+        None => current().expect("No Runtime").now(),
+    };
+
+    sleep_until(now + duration)
 }
 
 /// Future returned by [`sleep`](sleep) and
@@ -68,26 +95,146 @@ pub struct Sleep {
     ///
     /// This also stores the `deadline` value.
     entry: Arc<Entry>,
+
+    /// Clock installed by `with_clock` when the sleep was created, if any.
+    local: Option<Local>,
+
+    /// Set once `ignore_pause` was called, after which `entry` fires at a
+    /// real instant.
+    real: Option<Real>,
+}
+
+/// Deadline of a sleep created under `with_clock`.
+#[derive(Debug)]
+struct Local {
+    clock: Clock,
+
+    /// Deadline on `clock`. The deadline of `entry` is only its estimate on
+    /// the runtime's clock.
+    deadline: Instant,
+}
+
+/// State of a sleep pinned to real time by `ignore_pause`.
+#[derive(Debug)]
+struct Real {
+    /// Deadline reported by `Sleep::deadline`, on the clock the sleep ran on
+    /// before it was pinned.
+    logical: Instant,
+
+    /// Clock installed by `with_clock` when the sleep was created, if any.
+    /// A reset deadline is measured on it rather than on the runtime's clock.
+    clock: Option<Clock>,
+}
+
+impl Real {
+    /// Returns the current time on the clock the sleep ran on before it was
+    /// pinned.
+    fn now(&self) -> Instant {
+        match &self.clock {
+            Some(clock) => clock.now(),
+            None => current().expect("No Runtime").now(),
+        }
+    }
+}
+
+impl Local {
+    /// Returns the instant on the runtime's clock at which `clock` reaches
+    /// the deadline, provided it is not paused in the meantime.
+    fn runtime_deadline(&self) -> Instant {
+        current().expect("No Runtime").now()
+            + self.deadline.saturating_duration_since(self.clock.now())
+    }
 }
 
 impl Sleep {
     pub(crate) fn new_timeout(deadline: Instant, duration: Duration) -> Sleep {
         let handle = Handle::current();
-        let entry = Entry::new(&handle, deadline, duration);
 
-        Sleep { entry }
+        match local_clock::current() {
+            Some(clock) => {
+                let local = Local { clock, deadline };
+                let entry = Entry::new(&handle, local.runtime_deadline(), duration);
+
+                Sleep {
+                    entry,
+                    local: Some(local),
+                    real: None,
+                }
+            }
+            None => Sleep {
+                entry: Entry::new(&handle, deadline, duration),
+                local: None,
+                real: None,
+            },
+        }
     }
 
     /// Returns the instant at which the future will complete.
+    ///
+    /// For a sleep pinned to real time by [`ignore_pause`], this is the
+    /// deadline it had on the runtime's clock when it was pinned, or when
+    /// it was last reset, which the runtime's clock may reach later than
+    /// the sleep completes if it is paused in between.
+    ///
+    /// [`ignore_pause`]: method@Self::ignore_pause
     pub fn deadline(&self) -> Instant {
-        self.entry.time_ref().deadline
+        if let Some(real) = &self.real {
+            return real.logical;
+        }
+
+        match &self.local {
+            Some(local) => local.deadline,
+            None => self.entry.time_ref().deadline,
+        }
+    }
+
+    /// Returns the time left until the deadline, or zero if it has passed.
+    ///
+    /// The remaining time is measured on the clock the sleep runs on: the
+    /// runtime's clock, or the clock installed by [`with_clock`] when the
+    /// sleep was created. It therefore stops decreasing while that clock is
+    /// paused, unless the sleep was pinned to real time by [`ignore_pause`].
+    /// Like [`Instant::now`], reading the runtime's clock requires being
+    /// within its context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::{self, Duration};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let sleep = time::sleep(Duration::from_secs(10));
+    /// assert!(sleep.remaining() <= Duration::from_secs(10));
+    /// # }
+    /// ```
+    ///
+    /// [`with_clock`]: crate::time::with_clock
+    /// [`ignore_pause`]: method@Self::ignore_pause
+    pub fn remaining(&self) -> Duration {
+        if self.real.is_some() {
+            return self
+                .entry
+                .time_ref()
+                .deadline
+                .into_std()
+                .saturating_duration_since(std::time::Instant::now());
+        }
+
+        match &self.local {
+            Some(local) => local.deadline.saturating_duration_since(local.clock.now()),
+            None => self.deadline().saturating_duration_since(Instant::now()),
+        }
     }
 
     /// Returns `true` if `Sleep` has elapsed.
     ///
     /// A `Sleep` instance is elapsed when the requested duration has elapsed.
     pub fn is_elapsed(&self) -> bool {
-        self.entry.is_elapsed()
+        match &self.local {
+            Some(local) => local.clock.now() >= local.deadline,
+            None => self.entry.is_elapsed(),
+        }
     }
 
     /// Resets the `Sleep` instance to a new deadline.
@@ -97,7 +244,36 @@ impl Sleep {
     ///
     /// This function can be called both before and after the future has
     /// completed.
+    ///
+    /// A sleep pinned to real time by [`ignore_pause`] stays pinned: it
+    /// completes once the real time between now and `deadline` has elapsed.
+    /// That time is measured on the clock the sleep was created on, which is
+    /// the clock installed by [`with_clock`] at the time, if any.
+    ///
+    /// [`ignore_pause`]: method@Self::ignore_pause
+    /// [`with_clock`]: crate::time::with_clock
     pub fn reset(&mut self, deadline: Instant) {
+        if let Some(real) = &mut self.real {
+            let remaining = deadline.saturating_duration_since(real.now());
+            real.logical = deadline;
+
+            unsafe {
+                self.entry.time_mut().deadline =
+                    Instant::from_std(std::time::Instant::now() + remaining);
+            }
+
+            Entry::reset(&mut self.entry);
+            return;
+        }
+
+        let deadline = match &mut self.local {
+            Some(local) => {
+                local.deadline = deadline;
+                local.runtime_deadline()
+            }
+            None => deadline,
+        };
+
         unsafe {
             self.entry.time_mut().deadline = deadline;
         }
@@ -105,15 +281,94 @@ impl Sleep {
         Entry::reset(&mut self.entry);
     }
 
-    fn poll_elapsed(&self, cx: &mut task::Context<'_>) -> Poll<Result<(), Error>> {
+    /// Pins the sleep to real time, so that it completes once its remaining
+    /// time has elapsed on [`std::time::Instant`], whether or not the
+    /// runtime's clock is paused in the meantime.
+    ///
+    /// This is [`sleep_realtime`] for a sleep that already exists, which
+    /// lets a few watchdog timers run alongside ordinary sleeps without
+    /// changing how they are created. The time left is measured on the
+    /// runtime's clock, or on the clock installed by [`with_clock`], when
+    /// this is called. From then on, pausing or advancing that clock has no
+    /// effect on the sleep. Calling it again does nothing.
+    ///
+    /// Like a [`SleepRealtime`], a pinned sleep is fired by the runtime's
+    /// time driver, which keeps it apart from the timers on the clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::{self, Duration};
+    ///
+    /// # async fn dox() {
+    /// let mut watchdog = time::sleep(Duration::from_millis(10));
+    /// watchdog.ignore_pause();
+    ///
+    /// // Completes after 10ms of real time, even if the clock is paused.
+    /// watchdog.await;
+    /// # }
+    /// ```
+    ///
+    /// [`sleep_realtime`]: crate::time::sleep_realtime
+    /// [`SleepRealtime`]: crate::time::SleepRealtime
+    /// [`with_clock`]: crate::time::with_clock
+    pub fn ignore_pause(&mut self) {
+        if self.real.is_some() {
+            return;
+        }
+
+        let logical = self.deadline();
+        let deadline = std::time::Instant::now() + self.remaining();
+
+        self.real = Some(Real {
+            logical,
+            clock: self.local.take().map(|local| local.clock),
+        });
+
+        // The timer on the clock is replaced with one on real time.
+        Entry::cancel(&self.entry);
+        self.entry = Entry::new_real(&Handle::current(), deadline);
+    }
+
+    fn poll_elapsed(&mut self, cx: &mut task::Context<'_>) -> Poll<Result<(), Error>> {
         // Keep track of task budget
         let coop = ready!(crate::coop::poll_proceed(cx));
 
-        self.entry.poll_elapsed(cx).map(move |r| {
+        let res = match self.local {
+            Some(_) => self.poll_local(cx),
+            None => self.entry.poll_elapsed(cx),
+        };
+
+        res.map(move |r| {
             coop.made_progress();
             r
         })
     }
+
+    /// Polls a sleep created under `with_clock`.
+    ///
+    /// The runtime's timer fires at an estimate of when the local clock
+    /// reaches the deadline. The local clock may have been paused since the
+    /// estimate was made, so the timer is re-armed until the deadline is
+    /// actually reached.
+    fn poll_local(&mut self, cx: &mut task::Context<'_>) -> Poll<Result<(), Error>> {
+        let local = self.local.as_ref().expect("sleep has no local clock");
+
+        loop {
+            if local.clock.now() >= local.deadline {
+                return Poll::Ready(Ok(()));
+            }
+
+            ready!(local.clock.poll_resumed(cx));
+            ready!(self.entry.poll_elapsed(cx))?;
+
+            unsafe {
+                self.entry.time_mut().deadline = local.runtime_deadline();
+            }
+
+            Entry::reset(&mut self.entry);
+        }
+    }
 }
 
 impl Future for Sleep {
@@ -129,7 +384,7 @@ impl Future for Sleep {
         // Both cases are extremely rare, and pretty accurately fit into
         // "logic errors", so we just panic in this case. A user couldn't
         // really do much better if we passed the error onwards.
-        match ready!(self.poll_elapsed(cx)) {
+        match ready!(self.get_mut().poll_elapsed(cx)) {
             Ok(()) => Poll::Ready(()),
             Err(e) => panic!("timer error: {}", e),
         }
@@ -0,0 +1,100 @@
+use crate::time::driver::{Entry, Handle};
+use crate::time::Duration;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// Waits until `duration` of real time has elapsed, whether or not the
+/// runtime's clock is paused.
+///
+/// Unlike [`sleep`], which measures `duration` on the runtime's pausable
+/// clock, `sleep_realtime` measures it on [`std::time::Instant`]. It
+/// completes on time even while logical time is frozen, which suits
+/// watchdogs and other safety nets that must fire while the clock is paused.
+/// Advancing the runtime's clock does not bring it forward either.
+///
+/// Both kinds of sleep can be mixed freely, including as branches of the same
+/// `select!`: each completes according to its own source of time.
+///
+/// The runtime's time driver keeps real-time sleeps apart from the timer
+/// wheel and wakes up for the earliest of them even while the clock is
+/// paused. Like [`sleep`], it operates at millisecond granularity and must be
+/// called from within a runtime with the time driver enabled.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, Duration};
+///
+/// # async fn work() {}
+/// # async fn dox() {
+/// tokio::select! {
+///     _ = work() => {}
+///     _ = time::sleep_realtime(Duration::from_secs(30)) => {
+///         panic!("work is stuck, even accounting for pauses");
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [`sleep`]: crate::time::sleep()
+pub fn sleep_realtime(duration: Duration) -> SleepRealtime {
+    SleepRealtime::until(std::time::Instant::now() + duration)
+}
+
+/// Future returned by [`sleep_realtime`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SleepRealtime {
+    /// The timer that wakes the task once the deadline has passed.
+    entry: Arc<Entry>,
+
+    deadline: std::time::Instant,
+}
+
+impl SleepRealtime {
+    /// Creates a sleep that completes at the real instant `deadline`.
+    pub(crate) fn until(deadline: std::time::Instant) -> SleepRealtime {
+        SleepRealtime {
+            entry: Entry::new_real(&Handle::current(), deadline),
+            deadline,
+        }
+    }
+
+    /// Returns the real instant at which the future will complete.
+    pub fn deadline(&self) -> std::time::Instant {
+        self.deadline
+    }
+
+    /// Returns `true` if the deadline has passed.
+    pub fn is_elapsed(&self) -> bool {
+        std::time::Instant::now() >= self.deadline
+    }
+}
+
+impl Future for SleepRealtime {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Keep track of task budget
+        let coop = ready!(crate::coop::poll_proceed(cx));
+
+        // As for `Sleep`, an error is a misuse of the runtime or a
+        // pathological number of timers, so there is no point returning it.
+        match ready!(self.entry.poll_elapsed(cx)) {
+            Ok(()) => {
+                coop.made_progress();
+                Poll::Ready(())
+            }
+            Err(e) => panic!("timer error: {}", e),
+        }
+    }
+}
+
+impl Drop for SleepRealtime {
+    fn drop(&mut self) {
+        Entry::cancel(&self.entry);
+    }
+}
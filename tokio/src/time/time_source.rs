@@ -0,0 +1,182 @@
+use pausable_clock::PausableClock;
+
+use std::fmt;
+use std::time::Instant;
+
+/// A source of time for a runtime's clock.
+///
+/// A runtime built with [`Builder::time_source`] reads its time from the
+/// source instead of the system clock, which lets the time seen by tasks be
+/// driven by something else entirely, such as an external simulation master
+/// stepping several processes in lockstep.
+///
+/// The runtime's clock keeps its own bookkeeping on top of the source: it
+/// counts nested pauses, runs the pause and resume callbacks, wakes tasks
+/// waiting for a transition, and adds the time of [`advance`] calls to the
+/// instants the source returns. The source is only told when the clock
+/// actually pauses or resumes, and is expected to stop the time it reports
+/// in between.
+///
+/// While the clock is running, the time driver parks for the real time the
+/// next timer is due in and then checks the source again. A source whose
+/// time moves slower than real time thus fires timers late, never early.
+///
+/// The built-in [`PausableTimeSource`] and [`SystemTimeSource`] are the
+/// sources that runtimes read by default, with and without a pausable clock
+/// respectively.
+///
+/// # Examples
+///
+/// A source whose time only moves when it is told to:
+///
+/// ```
+/// use tokio::runtime;
+/// use tokio::time::TimeSource;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::{Duration, Instant};
+///
+/// #[derive(Debug)]
+/// struct Stepped(Mutex<Instant>);
+///
+/// impl TimeSource for Stepped {
+///     fn now(&self) -> Instant {
+///         *self.0.lock().unwrap()
+///     }
+///
+///     fn pause(&self) {}
+///
+///     fn resume(&self) {}
+/// }
+///
+/// let source = Arc::new(Stepped(Mutex::new(Instant::now())));
+///
+/// let rt = runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .time_source(Box::new(Arc::clone(&source)))
+///     .build();
+///
+/// // With the `test-util` feature, `build` rejects the time source.
+/// if let Ok(rt) = rt {
+///     let start = rt.block_on(async { tokio::time::Instant::now() });
+///     *source.0.lock().unwrap() += Duration::from_secs(5);
+///     let end = rt.block_on(async { tokio::time::Instant::now() });
+///
+///     assert_eq!(Duration::from_secs(5), end - start);
+/// }
+/// ```
+///
+/// [`Builder::time_source`]: crate::runtime::Builder::time_source
+/// [`advance`]: crate::runtime::Handle::advance_time
+pub trait TimeSource: fmt::Debug + Send + Sync + 'static {
+    /// Returns the current time of the source.
+    ///
+    /// The instants returned should not go backwards. The runtime's clock
+    /// never reports an instant earlier than one it already reported, so an
+    /// instant that does is clamped.
+    fn now(&self) -> Instant;
+
+    /// Called when the runtime's clock pauses, while no other thread can
+    /// pause or resume it.
+    fn pause(&self);
+
+    /// Called when the runtime's clock resumes, while no other thread can
+    /// pause or resume it.
+    fn resume(&self);
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for std::sync::Arc<T> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn pause(&self) {
+        (**self).pause()
+    }
+
+    fn resume(&self) {
+        (**self).resume()
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for Box<T> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn pause(&self) {
+        (**self).pause()
+    }
+
+    fn resume(&self) {
+        (**self).resume()
+    }
+}
+
+/// A time source that stops while the clock is paused.
+///
+/// This is the source a runtime with a pausable clock reads by default: it
+/// follows the system clock while running and stands still while paused, so
+/// that time spent paused is never observed.
+#[derive(Debug, Default)]
+pub struct PausableTimeSource {
+    clock: PausableClock,
+}
+
+impl PausableTimeSource {
+    /// Returns a new source, running from the current instant.
+    pub fn new() -> PausableTimeSource {
+        PausableTimeSource::default()
+    }
+}
+
+cfg_not_test_util! {
+    impl PausableTimeSource {
+        /// Returns a new source, paused at the current instant if `paused`.
+        pub(crate) fn starting(paused: bool) -> PausableTimeSource {
+            PausableTimeSource {
+                clock: PausableClock::new(std::time::Duration::from_secs(0), paused),
+            }
+        }
+    }
+}
+
+impl TimeSource for PausableTimeSource {
+    fn now(&self) -> Instant {
+        self.clock.now_std()
+    }
+
+    fn pause(&self) {
+        self.clock.pause();
+    }
+
+    fn resume(&self) {
+        self.clock.resume();
+    }
+}
+
+/// A time source that follows the system clock.
+///
+/// This is the source of runtimes without a pausable clock. It ignores
+/// pauses and resumes, so its time keeps running while the clock is
+/// paused, and the time spent paused is observed once it resumes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemTimeSource {
+    _p: (),
+}
+
+impl SystemTimeSource {
+    /// Returns a new source.
+    pub fn new() -> SystemTimeSource {
+        SystemTimeSource::default()
+    }
+}
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn pause(&self) {}
+
+    fn resume(&self) {}
+}
@@ -25,6 +25,13 @@ use std::task::{self, Poll};
 /// The original future may be obtained by calling [`Timeout::into_inner`]. This
 /// consumes the `Timeout`.
 ///
+/// # Pausing
+///
+/// The deadline is computed from the runtime's clock. On a runtime with a
+/// pausable clock, the countdown stops while the clock is paused: the timeout
+/// only fires once `duration` of logical time has elapsed, however long the
+/// clock stays paused.
+///
 /// # Examples
 ///
 /// Create a new `Timeout` set to expire in 10 milliseconds.
@@ -210,6 +210,15 @@ impl Level {
 
         ret
     }
+
+    /// Moves every entry of the level to `items`.
+    pub(crate) fn drain(&mut self, items: &mut Vec<OwnedItem>) {
+        for slot in 0..LEVEL_MULT {
+            while let Some(item) = self.pop_entry_slot(slot) {
+                items.push(item);
+            }
+        }
+    }
 }
 
 impl fmt::Debug for Level {
@@ -29,6 +29,9 @@ pub(crate) struct Wheel {
     /// The number of milliseconds elapsed since the wheel started.
     elapsed: u64,
 
+    /// The number of entries in the wheel.
+    len: usize,
+
     /// Timer wheel.
     ///
     /// Levels:
@@ -55,7 +58,11 @@ impl Wheel {
     pub(crate) fn new() -> Wheel {
         let levels = (0..NUM_LEVELS).map(Level::new).collect();
 
-        Wheel { elapsed: 0, levels }
+        Wheel {
+            elapsed: 0,
+            len: 0,
+            levels,
+        }
     }
 
     /// Return the number of milliseconds that have elapsed since the timing
@@ -64,6 +71,11 @@ impl Wheel {
         self.elapsed
     }
 
+    /// Return the number of entries in the timing wheel.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
     /// Insert an entry into the timing wheel.
     ///
     /// # Arguments
@@ -100,6 +112,7 @@ impl Wheel {
         let level = self.level_for(when);
 
         self.levels[level].add_entry(when, item);
+        self.len += 1;
 
         debug_assert!({
             self.levels[level]
@@ -117,6 +130,20 @@ impl Wheel {
         let level = self.level_for(when);
 
         self.levels[level].remove_entry(when, item);
+        self.len -= 1;
+    }
+
+    /// Removes every entry from the timing wheel, leaving its elapsed time
+    /// unchanged.
+    pub(crate) fn drain(&mut self) -> Vec<OwnedItem> {
+        let mut items = Vec::with_capacity(self.len);
+
+        for level in &mut self.levels {
+            level.drain(&mut items);
+        }
+
+        self.len = 0;
+        items
     }
 
     /// Instant at which to poll
@@ -139,6 +166,7 @@ impl Wheel {
             match expiration {
                 Some(ref expiration) => {
                     if let Some(item) = self.poll_expiration(expiration) {
+                        self.len -= 1;
                         return Some(item);
                     }
 
@@ -31,6 +31,11 @@ cfg_rt_multi_thread! {
 
 pub(crate) mod trace;
 
+/// Callback run on a runtime event, such as a thread starting or the clock
+/// pausing.
+#[cfg(any(feature = "rt", feature = "time"))]
+pub(crate) type Callback = std::sync::Arc<dyn Fn() + Send + Sync>;
+
 #[cfg(any(feature = "macros", feature = "stream"))]
 #[cfg_attr(not(feature = "macros"), allow(unreachable_pub))]
 pub use rand::thread_rng_n;
@@ -17,3 +17,13 @@ async fn test_macro_is_resilient_to_shadowing() {
     .await
     .unwrap();
 }
+
+#[cfg(feature = "full")]
+#[tokio::test(start_paused = true)]
+async fn test_macro_can_start_paused() {
+    assert!(tokio::time::is_paused());
+
+    let handle = tokio::runtime::Handle::current();
+    assert_eq!(Ok(true), handle.resume_time());
+    assert!(!tokio::time::is_paused());
+}
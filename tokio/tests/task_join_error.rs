@@ -0,0 +1,336 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::task::{CancelReason, JoinError, TaskOutcome};
+
+/// Runs `panic` when dropped.
+struct PanicOnDrop<F: FnOnce()>(Option<F>);
+
+impl<F: FnOnce()> Drop for PanicOnDrop<F> {
+    fn drop(&mut self) {
+        (self.0.take().unwrap())();
+    }
+}
+
+/// A task panicking while polled propagates the panic to the runtime, so the
+/// `JoinError` comes from a future that panics when dropped on cancellation.
+async fn panicked_with<F>(panic: F) -> JoinError
+where
+    F: FnOnce() + Send + 'static,
+{
+    let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+    let handle = tokio::spawn(async move {
+        let _guard = PanicOnDrop(Some(panic));
+        let _ = rx.await;
+    });
+
+    tokio::task::yield_now().await;
+    handle.abort();
+    handle.await.unwrap_err()
+}
+
+async fn panicked() -> JoinError {
+    panicked_with(|| panic!("boom")).await
+}
+
+async fn cancelled() -> JoinError {
+    let handle = tokio::spawn(async {
+        futures::future::pending::<()>().await;
+    });
+    handle.abort();
+    handle.await.unwrap_err()
+}
+
+#[tokio::test]
+async fn into_panic_returns_payload() {
+    let payload = panicked().await.into_panic();
+
+    assert_eq!(Some(&"boom"), payload.downcast_ref::<&str>());
+}
+
+#[tokio::test]
+async fn try_into_panic_returns_err_for_cancelled() {
+    let err = cancelled().await;
+
+    let err = err.try_into_panic().unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[tokio::test]
+async fn is_panic_for_panicked_task() {
+    let err = panicked().await;
+
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+}
+
+#[tokio::test]
+async fn is_panic_for_cancelled_task() {
+    let err = cancelled().await;
+
+    assert!(!err.is_panic());
+    assert!(err.is_cancelled());
+}
+
+#[tokio::test]
+async fn display_includes_panic_message() {
+    let err = panicked().await;
+    assert_eq!(r#"panicked: "boom""#, err.to_string());
+
+    let err = panicked_with(|| panic!("{} {}", "formatted", "boom")).await;
+    assert_eq!(r#"panicked: "formatted boom""#, err.to_string());
+}
+
+#[tokio::test]
+async fn display_falls_back_for_non_string_payload() {
+    let err = panicked_with(|| std::panic::resume_unwind(Box::new(42))).await;
+
+    assert_eq!("panic", err.to_string());
+}
+
+#[tokio::test]
+async fn display_cancelled() {
+    assert_eq!("cancelled", cancelled().await.to_string());
+}
+
+#[tokio::test]
+async fn id_identifies_the_failed_task() {
+    let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+    let first = tokio::spawn(async move {
+        let _ = rx.await;
+    });
+    let second = tokio::spawn(futures::future::pending::<()>());
+
+    let first_id = first.id();
+    let second_id = second.id();
+    assert_ne!(first_id, second_id);
+
+    second.abort();
+    assert_eq!(second_id, second.await.unwrap_err().id());
+
+    first.abort();
+    assert_eq!(first_id, first.await.unwrap_err().id());
+}
+
+#[tokio::test]
+async fn id_of_panicked_task() {
+    let err = panicked().await;
+    let other = cancelled().await;
+
+    assert_ne!(err.id(), other.id());
+}
+
+#[tokio::test]
+async fn into_io_error_includes_panic_message() {
+    let err = panicked().await.into_io_error();
+    assert_eq!(std::io::ErrorKind::Other, err.kind());
+    assert_eq!("task panicked: boom", err.to_string());
+
+    let err = panicked_with(|| std::panic::resume_unwind(Box::new(42)))
+        .await
+        .into_io_error();
+    assert_eq!("task panicked", err.to_string());
+}
+
+#[tokio::test]
+async fn into_io_error_for_cancelled_task() {
+    let err = cancelled().await.into_io_error();
+    assert_eq!(std::io::ErrorKind::Other, err.kind());
+    assert_eq!("task was cancelled", err.to_string());
+}
+
+#[tokio::test]
+async fn from_join_error_for_io_error_keeps_panic_message() {
+    let err: std::io::Error = panicked().await.into();
+    assert_eq!("task panicked: boom", err.to_string());
+}
+
+#[tokio::test]
+async fn clone_shallow_shares_the_panic() {
+    let err = panicked().await;
+    let copy = err.clone_shallow();
+
+    assert!(copy.is_panic());
+    assert!(!copy.is_cancelled());
+    assert_eq!(err.id(), copy.id());
+    assert_eq!(err.to_string(), copy.to_string());
+
+    // The payload cannot be taken while it is shared.
+    let err = err.try_into_panic().unwrap_err();
+    assert_eq!("task panicked: boom", copy.into_io_error().to_string());
+
+    let payload = err.into_panic();
+    assert_eq!(Some(&"boom"), payload.downcast_ref::<&str>());
+}
+
+#[tokio::test]
+#[should_panic]
+async fn into_panic_panics_while_the_payload_is_shared() {
+    let err = panicked().await;
+    let _copy = err.clone_shallow();
+
+    err.into_panic();
+}
+
+#[tokio::test]
+async fn clone_shallow_of_cancelled_task() {
+    let err = cancelled().await;
+    let copy = err.clone_shallow();
+
+    assert!(copy.is_cancelled());
+    assert_eq!(err.id(), copy.id());
+    assert_eq!("cancelled", copy.to_string());
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+async fn panicked_with_error() -> JoinError {
+    panicked_with(|| {
+        let error: BoxError = Box::new(std::io::Error::new(std::io::ErrorKind::Other, "disk full"));
+        std::panic::resume_unwind(Box::new(error))
+    })
+    .await
+}
+
+#[tokio::test]
+async fn source_is_the_error_the_task_panicked_with() {
+    use std::error::Error;
+
+    let err = panicked_with_error().await;
+    assert!(err.is_panic());
+    assert_eq!("panicked: disk full", err.to_string());
+
+    let source = err.source().unwrap();
+    assert_eq!("disk full", source.to_string());
+    assert!(source.downcast_ref::<std::io::Error>().is_some());
+}
+
+#[tokio::test]
+async fn source_is_none_for_other_payloads() {
+    use std::error::Error;
+
+    assert!(panicked().await.source().is_none());
+    assert!(cancelled().await.source().is_none());
+}
+
+#[tokio::test]
+async fn into_panic_returns_error_payload() {
+    let payload = panicked_with_error().await.into_panic();
+    let error = payload.downcast::<BoxError>().unwrap();
+
+    assert_eq!("disk full", error.to_string());
+}
+
+#[tokio::test]
+async fn clone_shallow_shares_the_error_source() {
+    use std::error::Error;
+
+    let err = panicked_with_error().await;
+    let copy = err.clone_shallow();
+
+    assert_eq!("disk full", copy.source().unwrap().to_string());
+    assert_eq!("task panicked: disk full", copy.into_io_error().to_string());
+    assert!(err.try_into_panic().is_ok());
+}
+
+#[tokio::test]
+async fn cancel_reason_of_an_aborted_task() {
+    assert_eq!(
+        Some(CancelReason::Aborted),
+        cancelled().await.cancel_reason()
+    );
+}
+
+#[test]
+fn cancel_reason_of_a_task_lost_to_shutdown() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let handle = rt.spawn(futures::future::pending::<()>());
+    drop(rt);
+
+    let err = futures::executor::block_on(handle).unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(Some(CancelReason::RuntimeShutdown), err.cancel_reason());
+}
+
+#[tokio::test]
+async fn cancel_reason_is_none_for_panics() {
+    assert_eq!(None, panicked().await.cancel_reason());
+}
+
+#[tokio::test]
+async fn into_outcome_of_a_panicked_task_surfaces_the_payload() {
+    match panicked().await.into_outcome() {
+        TaskOutcome::Panicked(payload) => {
+            assert_eq!(Some(&"boom"), payload.downcast_ref::<&str>());
+        }
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn into_outcome_of_a_cancelled_task() {
+    match cancelled().await.into_outcome() {
+        TaskOutcome::Cancelled => {}
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn into_outcome_once_the_payload_is_no_longer_shared() {
+    let err = panicked_with_error().await;
+    drop(err.clone_shallow());
+
+    match err.into_outcome() {
+        TaskOutcome::Panicked(payload) => {
+            let error = payload.downcast::<BoxError>().unwrap();
+            assert_eq!("disk full", error.to_string());
+        }
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn into_outcome_while_the_payload_is_shared() {
+    let err = panicked().await;
+    let copy = err.clone_shallow();
+
+    let err = match err.into_outcome() {
+        TaskOutcome::PanickedShared(err) => err,
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    };
+
+    drop(copy);
+
+    match err.into_outcome() {
+        TaskOutcome::Panicked(payload) => {
+            assert_eq!(Some(&"boom"), payload.downcast_ref::<&str>());
+        }
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn cancelled_with_reports_the_reason() {
+    let handle = tokio::spawn(async {});
+    let err = JoinError::cancelled_with(handle.id(), CancelReason::RuntimeShutdown);
+
+    assert!(err.is_cancelled());
+    assert!(!err.is_panic());
+    assert_eq!(handle.id(), err.id());
+    assert_eq!(Some(CancelReason::RuntimeShutdown), err.cancel_reason());
+    assert_eq!("cancelled", err.to_string());
+}
+
+#[tokio::test]
+async fn cancelled_with_a_timeout() {
+    let handle = tokio::spawn(async {});
+    let err = JoinError::cancelled_with(handle.id(), CancelReason::Timeout);
+
+    assert!(err.is_cancelled());
+    assert_eq!(Some(CancelReason::Timeout), err.cancel_reason());
+}
@@ -29,10 +29,13 @@ async fn can_pause_after_resume() {
 }
 
 #[tokio::test]
-#[should_panic]
-async fn freezing_time_while_frozen_panics() {
+async fn freezing_time_while_frozen_does_nothing() {
     time::pause();
     time::pause();
+    assert!(time::is_paused());
+
+    time::resume();
+    assert!(!time::is_paused());
 }
 
 #[tokio::test]
@@ -42,9 +45,410 @@ async fn advancing_time_when_time_is_not_frozen_panics() {
 }
 
 #[tokio::test]
-#[should_panic]
-async fn resuming_time_when_not_frozen_panics() {
+async fn resuming_time_when_not_frozen_does_nothing() {
     time::pause();
     time::resume();
     time::resume();
+    assert!(!time::is_paused());
+
+    let handle = tokio::runtime::Handle::current();
+    assert_eq!(Ok(false), handle.resume_time());
+    assert!(!time::is_paused());
+}
+
+#[tokio::test]
+async fn wait_for_resume_completes_immediately_when_not_paused() {
+    time::wait_for_resume().await;
+}
+
+#[tokio::test]
+async fn wait_for_resume_completes_on_resume() {
+    use tokio_test::{assert_pending, assert_ready, task};
+
+    time::pause();
+
+    let mut wait = task::spawn(time::wait_for_resume());
+    assert_pending!(wait.poll());
+
+    time::resume();
+    assert!(wait.is_woken());
+    assert_ready!(wait.poll());
+}
+
+#[tokio::test]
+async fn wait_for_pause_completes_immediately_when_paused() {
+    time::pause();
+    time::wait_for_pause().await;
+}
+
+#[tokio::test]
+async fn wait_for_pause_completes_on_pause() {
+    use tokio_test::{assert_pending, assert_ready, task};
+
+    let mut wait = task::spawn(time::wait_for_pause());
+    assert_pending!(wait.poll());
+
+    time::pause();
+    assert!(wait.is_woken());
+    assert_ready!(wait.poll());
+}
+
+#[tokio::test]
+async fn pause_events_yields_transitions() {
+    use tokio::time::PauseEvent;
+
+    let mut events = time::pause_events();
+
+    time::pause();
+    time::resume();
+
+    assert_eq!(Some(PauseEvent::Paused), events.recv().await);
+    assert_eq!(Some(PauseEvent::Resumed), events.recv().await);
+}
+
+#[tokio::test]
+async fn pause_events_reports_lag() {
+    use tokio::time::PauseEvent;
+
+    let mut events = time::pause_events();
+
+    for _ in 0..100 {
+        time::pause();
+        time::resume();
+    }
+
+    assert!(matches!(events.recv().await, Some(PauseEvent::Lagged(_))));
+    assert!(matches!(
+        events.recv().await,
+        Some(PauseEvent::Paused) | Some(PauseEvent::Resumed)
+    ));
+}
+
+#[tokio::test]
+async fn paused_duration_accumulates_real_time_spent_paused() {
+    assert_eq!(Duration::from_secs(0), time::paused_duration());
+
+    time::pause();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert!(time::paused_duration() >= Duration::from_millis(10));
+    time::resume();
+
+    let paused = time::paused_duration();
+    assert!(paused >= Duration::from_millis(10));
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert_eq!(paused, time::paused_duration());
+}
+
+#[tokio::test]
+async fn nested_pause_resumes_when_balanced() {
+    use tokio::runtime::Handle;
+
+    let handle = Handle::current();
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+    assert_eq!(2, handle.pause_depth());
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert!(handle.is_time_paused());
+
+    assert_eq!(Ok(true), handle.resume_time());
+    assert!(!handle.is_time_paused());
+}
+
+#[tokio::test]
+async fn run_if_paused_and_run_if_resumed() {
+    assert_eq!(None, time::run_if_paused(|| 1));
+    assert_eq!(Some(2), time::run_if_resumed(|| 2));
+
+    time::pause();
+
+    assert_eq!(Some(1), time::run_if_paused(|| 1));
+    assert_eq!(None, time::run_if_resumed(|| 2));
+
+    // The clock is held paused while the action runs.
+    assert_eq!(Some(true), time::run_if_paused(time::is_paused));
+}
+
+#[test]
+fn now_is_monotonic_under_concurrent_pause_and_resume() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use tokio::runtime::Builder;
+
+    let rt = Builder::new_current_thread().enable_time().build().unwrap();
+    let done = Arc::new(AtomicBool::new(false));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = rt.handle().clone();
+            let done = done.clone();
+
+            std::thread::spawn(move || {
+                let _enter = handle.enter();
+                let mut last = Instant::now();
+
+                while !done.load(Ordering::Relaxed) {
+                    let now = Instant::now();
+                    assert!(now >= last, "time went backwards");
+                    last = now;
+                }
+            })
+        })
+        .collect();
+
+    {
+        let handle = rt.handle().clone();
+        let _enter = handle.enter();
+
+        for _ in 0..10_000 {
+            assert_eq!(Ok(true), handle.pause_time());
+            handle.advance_time(Duration::from_micros(1));
+            assert_eq!(Ok(true), handle.resume_time());
+        }
+    }
+
+    done.store(true, Ordering::Relaxed);
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[tokio::test]
+async fn is_paused_with_any_load_ordering() {
+    use std::sync::atomic::Ordering;
+
+    assert!(!time::is_paused());
+    assert!(!time::is_paused_ordered(Ordering::Relaxed));
+
+    time::pause();
+
+    assert!(time::is_paused());
+    assert!(time::is_paused_ordered(Ordering::Relaxed));
+    assert!(time::is_paused_ordered(Ordering::Acquire));
+}
+
+#[tokio::test]
+async fn try_pause_and_try_resume() {
+    use tokio::time::error::ClockError;
+
+    assert_eq!(Ok(true), time::try_pause());
+    assert_eq!(Err(ClockError::AlreadyPaused), time::try_pause());
+
+    assert_eq!(Ok(true), time::try_resume());
+    assert_eq!(Ok(false), time::try_resume());
+}
+
+#[tokio::test]
+async fn clock_snapshot_is_consistent() {
+    time::pause();
+
+    let snapshot = time::clock_snapshot();
+    assert!(snapshot.is_paused());
+    assert_eq!(snapshot.now(), Instant::now());
+
+    time::advance(Duration::from_millis(10)).await;
+
+    let advanced = time::clock_snapshot();
+    assert_eq!(advanced.now(), snapshot.now() + Duration::from_millis(10));
+    assert_eq!(advanced.elapsed_millis(), snapshot.elapsed_millis() + 10);
+}
+
+#[tokio::test]
+async fn resume_notify_fires_on_resume() {
+    use tokio_test::{assert_pending, assert_ready};
+
+    let resumed = time::resume_notify();
+    let mut notified = tokio_test::task::spawn(resumed.notified());
+    assert_pending!(notified.poll());
+
+    time::pause();
+    assert_pending!(notified.poll());
+
+    time::resume();
+    assert!(notified.is_woken());
+    assert_ready!(notified.poll());
+}
+
+#[tokio::test]
+async fn elapsed_is_pause_aware() {
+    time::pause();
+
+    let start = time::elapsed();
+    time::advance(Duration::from_micros(1500)).await;
+    assert_eq!(start + Duration::from_micros(1500), time::elapsed());
+}
+
+#[tokio::test]
+async fn pause_generation_detects_missed_transitions() {
+    let generation = time::pause_generation();
+
+    time::pause();
+    time::resume();
+
+    assert!(!time::is_paused());
+    assert_eq!(generation + 2, time::pause_generation());
+}
+
+#[tokio::test]
+async fn wait_for_resume_timeout_elapses_in_real_time() {
+    time::pause();
+
+    // The paused test clock never advances, but the timeout is real time.
+    assert!(!time::wait_for_resume_timeout(Duration::from_millis(10)).await);
+
+    time::resume();
+    assert!(time::wait_for_resume_timeout(Duration::from_millis(10)).await);
+}
+
+#[tokio::test]
+async fn frozen_now_matches_now_while_paused() {
+    assert_eq!(time::frozen_now(), None);
+
+    time::pause();
+    let frozen = time::frozen_now().unwrap();
+    assert_eq!(Instant::now(), frozen);
+
+    time::advance(Duration::from_secs(1)).await;
+    assert_eq!(time::frozen_now(), Some(frozen + Duration::from_secs(1)));
+
+    time::resume();
+    assert_eq!(time::frozen_now(), None);
+}
+
+#[tokio::test]
+async fn on_pause_completes_on_next_pause_only() {
+    use tokio_test::{assert_pending, assert_ready, task};
+
+    time::pause();
+
+    // A pause that happened before the future was created does not count.
+    let mut next = task::spawn(time::on_pause());
+    assert_pending!(next.poll());
+
+    time::resume();
+    assert_pending!(next.poll());
+
+    time::pause();
+    assert!(next.is_woken());
+    assert_ready!(next.poll());
+    time::resume();
+}
+
+#[tokio::test]
+async fn dropping_on_pause_withdraws_its_registration() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use tokio_test::task;
+
+    let mut task = task::spawn(());
+
+    for _ in 0..10 {
+        let mut next = time::on_pause();
+
+        task.enter(|cx, _| {
+            assert!(Pin::new(&mut next).poll(cx).is_pending());
+            assert!(Pin::new(&mut next).poll(cx).is_pending());
+        });
+        assert_eq!(2, task.waker_ref_count());
+
+        drop(next);
+        assert_eq!(1, task.waker_ref_count());
+    }
+}
+
+#[tokio::test]
+async fn dropping_wait_for_resume_withdraws_its_registration() {
+    use std::future::Future;
+    use tokio_test::task;
+
+    let mut task = task::spawn(());
+
+    time::pause();
+
+    for _ in 0..10 {
+        let mut resumed = Box::pin(time::wait_for_resume());
+
+        task.enter(|cx, _| {
+            assert!(resumed.as_mut().poll(cx).is_pending());
+            assert!(resumed.as_mut().poll(cx).is_pending());
+        });
+        assert_eq!(2, task.waker_ref_count());
+
+        drop(resumed);
+        assert_eq!(1, task.waker_ref_count());
+    }
+
+    time::resume();
+
+    for _ in 0..10 {
+        let mut paused = Box::pin(time::wait_for_pause());
+
+        task.enter(|cx, _| assert!(paused.as_mut().poll(cx).is_pending()));
+        assert_eq!(2, task.waker_ref_count());
+
+        drop(paused);
+        assert_eq!(1, task.waker_ref_count());
+    }
+}
+
+#[tokio::test]
+async fn wait_for_resume_losing_a_select_leaves_no_waker_behind() {
+    use tokio_test::{assert_ready, task};
+
+    time::pause();
+
+    let mut task = task::spawn(async {
+        for _ in 0..1000 {
+            tokio::select! {
+                _ = time::wait_for_resume() => panic!("the clock is paused"),
+                _ = time::wait_for_pause() => {}
+            }
+        }
+    });
+
+    assert_ready!(task.poll());
+    assert_eq!(1, task.waker_ref_count());
+
+    time::resume();
+}
+
+#[tokio::test]
+async fn sleep_realtime_ignores_advance() {
+    use tokio_test::{assert_pending, task};
+
+    time::pause();
+
+    let mut sleep = task::spawn(time::sleep_realtime(Duration::from_millis(50)));
+    assert_pending!(sleep.poll());
+
+    time::advance(Duration::from_secs(60)).await;
+    assert!(!sleep.is_elapsed());
+    assert_pending!(sleep.poll());
+
+    time::resume();
+    sleep.await;
+}
+
+#[tokio::test]
+async fn run_unresumable_is_unsupported() {
+    use tokio::time::error::ClockError;
+
+    time::pause();
+
+    let ran = std::cell::Cell::new(false);
+    assert_eq!(
+        Err(ClockError::Unsupported),
+        time::run_unresumable(|| ran.set(true))
+    );
+    assert!(!ran.get());
+}
+
+#[tokio::test]
+async fn clock_kind_is_test() {
+    let handle = tokio::runtime::Handle::current();
+
+    assert_eq!(time::ClockKind::Test, handle.clock_kind());
 }
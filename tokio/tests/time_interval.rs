@@ -44,6 +44,82 @@ async fn usage() {
     assert_pending!(poll_next(&mut i));
 }
 
+#[tokio::test]
+async fn burst() {
+    let (start, mut i) = paused_interval(time::MissedTickBehavior::Burst).await;
+
+    // Jump past two ticks; both are yielded immediately.
+    time::advance(ms(650)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(300));
+    assert_ready_eq!(poll_next(&mut i), start + ms(600));
+    assert_pending!(poll_next(&mut i));
+
+    time::advance(ms(250)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(900));
+    assert_pending!(poll_next(&mut i));
+}
+
+#[tokio::test]
+async fn delay() {
+    let (start, mut i) = paused_interval(time::MissedTickBehavior::Delay).await;
+
+    time::advance(ms(650)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(300));
+    assert_pending!(poll_next(&mut i));
+
+    // The schedule restarts one period after the missed tick was observed.
+    time::advance(ms(299)).await;
+    assert_pending!(poll_next(&mut i));
+
+    time::advance(ms(1)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(951));
+    assert_pending!(poll_next(&mut i));
+}
+
+#[tokio::test]
+async fn skip() {
+    let (start, mut i) = paused_interval(time::MissedTickBehavior::Skip).await;
+
+    time::advance(ms(650)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(300));
+    assert_pending!(poll_next(&mut i));
+
+    // The interval realigns with its original schedule.
+    time::advance(ms(250)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(900));
+    assert_pending!(poll_next(&mut i));
+}
+
+#[tokio::test]
+async fn pause_does_not_miss_ticks() {
+    time::pause();
+
+    let start = Instant::now();
+    time::advance(ms(1)).await;
+
+    let mut i = task::spawn(time::interval_at(start, ms(300)));
+    i.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+    assert_ready_eq!(poll_next(&mut i), start);
+
+    // While frozen, no ticks elapse no matter how long we wait on the wall
+    // clock.
+    std::thread::sleep(ms(50));
+    assert_pending!(poll_next(&mut i));
+
+    time::advance(ms(300)).await;
+    assert_ready_eq!(poll_next(&mut i), start + ms(300));
+    assert_pending!(poll_next(&mut i));
+}
+
+#[test]
+fn missed_tick_behavior_default() {
+    assert_eq!(
+        time::MissedTickBehavior::default(),
+        time::MissedTickBehavior::Burst
+    );
+}
+
 #[tokio::test]
 async fn usage_stream() {
     use tokio::stream::StreamExt;
@@ -58,6 +134,26 @@ async fn usage_stream() {
     assert!(start.elapsed() > ms(20));
 }
 
+/// Returns a frozen interval with a 300ms period that has yielded its first
+/// tick.
+async fn paused_interval(
+    behavior: time::MissedTickBehavior,
+) -> (Instant, task::Spawn<time::Interval>) {
+    time::pause();
+
+    let start = Instant::now();
+    time::advance(ms(1)).await;
+
+    let mut i = task::spawn(time::interval_at(start, ms(300)));
+    i.set_missed_tick_behavior(behavior);
+    assert_eq!(i.missed_tick_behavior(), behavior);
+
+    assert_ready_eq!(poll_next(&mut i), start);
+    assert_pending!(poll_next(&mut i));
+
+    (start, i)
+}
+
 fn poll_next(interval: &mut task::Spawn<time::Interval>) -> Poll<Instant> {
     interval.enter(|cx, mut interval| {
         tokio::pin! {
@@ -0,0 +1,176 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::time::{self, Duration, Instant, LocalClock};
+use tokio_test::{assert_pending, assert_ready, task};
+
+#[tokio::test]
+async fn with_clock_overrides_now_and_is_paused() {
+    let clock = LocalClock::new(true);
+    assert!(clock.is_paused());
+
+    time::with_clock(clock.clone(), async {
+        assert!(time::is_paused());
+        assert_eq!(Instant::now(), clock.now());
+    })
+    .await;
+
+    // Leaving the scope restores the runtime's clock.
+    assert!(!time::is_paused());
+}
+
+#[tokio::test]
+async fn pause_in_scope_only_affects_local_clock() {
+    let clock = LocalClock::new(false);
+
+    time::with_clock(clock.clone(), async {
+        assert_eq!(time::try_pause(), Ok(true));
+    })
+    .await;
+
+    assert!(clock.is_paused());
+    assert!(!time::is_paused());
+    assert!(clock.resume());
+}
+
+#[tokio::test]
+async fn sleep_waits_for_local_clock_to_resume() {
+    let clock = LocalClock::new(true);
+
+    let mut sleep = task::spawn(time::with_clock(clock.clone(), async {
+        time::sleep(Duration::from_millis(10)).await;
+    }));
+    assert_pending!(sleep.poll());
+
+    // The runtime's clock keeps running, but the local clock does not.
+    std::thread::sleep(Duration::from_millis(30));
+    assert_pending!(sleep.poll());
+
+    assert!(clock.resume());
+    assert!(sleep.is_woken());
+
+    time::timeout(Duration::from_secs(5), sleep).await.unwrap();
+}
+
+#[tokio::test]
+async fn local_clocks_are_independent() {
+    let running = LocalClock::new(false);
+    let paused = LocalClock::new(true);
+
+    let mut stalled = task::spawn(time::with_clock(paused.clone(), async {
+        time::sleep(Duration::from_millis(10)).await;
+    }));
+    assert_pending!(stalled.poll());
+
+    time::with_clock(running, async {
+        time::sleep(Duration::from_millis(10)).await;
+    })
+    .await;
+
+    assert_pending!(stalled.poll());
+    assert!(!stalled.is_woken());
+
+    assert!(paused.resume());
+    assert!(stalled.is_woken());
+
+    time::timeout(Duration::from_secs(5), stalled)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn sleep_deadline_is_on_local_clock() {
+    let clock = LocalClock::new(true);
+
+    let sleep = time::with_clock(clock.clone(), async {
+        time::sleep(Duration::from_millis(10))
+    })
+    .await;
+
+    assert_eq!(sleep.deadline(), clock.now() + Duration::from_millis(10));
+    assert!(!sleep.is_elapsed());
+
+    // The sleep keeps following the local clock outside of `with_clock`.
+    let mut sleep = task::spawn(sleep);
+    assert_pending!(sleep.poll());
+
+    clock.resume();
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(sleep.is_elapsed());
+    assert_ready!(sleep.poll());
+}
+
+#[test]
+fn ptr_eq_identifies_clones() {
+    let clock = LocalClock::new(false);
+    let other = LocalClock::new(false);
+
+    assert!(clock.ptr_eq(&clock));
+    assert!(clock.ptr_eq(&clock.clone()));
+    assert!(!clock.ptr_eq(&other));
+    assert!(!other.ptr_eq(&clock));
+}
+
+#[test]
+fn runtime_uses_the_clock_it_was_built_with() {
+    use tokio::runtime;
+
+    let clock = LocalClock::new(false);
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_time()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+
+    assert!(rt.handle().uses_clock(&clock));
+    assert!(rt.handle().clone().uses_clock(&clock.clone()));
+    assert!(!rt.handle().uses_clock(&LocalClock::new(false)));
+
+    let other = runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+
+    assert!(!other.handle().uses_clock(&clock));
+}
+
+#[tokio::test]
+async fn sleep_remaining_reads_local_clock() {
+    let clock = LocalClock::new(true);
+
+    let sleep = time::with_clock(clock.clone(), async {
+        time::sleep(Duration::from_secs(10))
+    })
+    .await;
+
+    // The local clock is paused, so no time passes for the sleep.
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    assert_eq!(Duration::from_secs(10), sleep.remaining());
+
+    assert!(clock.resume());
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    assert!(sleep.remaining() < Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn sleep_ignoring_pause_is_reset_on_local_clock() {
+    let clock = LocalClock::new(true);
+
+    let mut sleep = time::with_clock(clock.clone(), async {
+        time::sleep(Duration::from_secs(10))
+    })
+    .await;
+    sleep.ignore_pause();
+
+    // The local clock falls behind the runtime's while it is paused.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+
+    let deadline = clock.now() + Duration::from_millis(20);
+    sleep.reset(deadline);
+    assert_eq!(deadline, sleep.deadline());
+    assert!(!sleep.is_elapsed());
+    assert!(sleep.remaining() > Duration::from_millis(10));
+
+    sleep.await;
+}
@@ -0,0 +1,585 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+// `tokio-test` enables the `test-util` feature for these tests, so they run on
+// the `test-util` clock. tests-integration/tests/time_pausable.rs checks that
+// the pausable clock reports the same results.
+
+use tokio::runtime::{self, Runtime};
+use tokio::time::error::ClockError;
+use tokio::time::Duration;
+
+fn pausable_rt() -> Runtime {
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .pausable_time(false, Duration::from_secs(0))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn redundant_pause_returns_false() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(false), handle.pause_time());
+    assert_eq!(2, handle.pause_depth());
+
+    rt.block_on(async {
+        assert_eq!(Err(ClockError::AlreadyPaused), tokio::time::try_pause());
+
+        // Unlike `pause_time`, `time::pause` does not nest.
+        tokio::time::pause();
+        assert!(tokio::time::is_paused());
+    });
+    assert_eq!(2, handle.pause_depth());
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert_eq!(Ok(true), handle.resume_time());
+    assert!(!handle.is_time_paused());
+}
+
+#[test]
+fn redundant_resume_returns_false() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(Ok(false), handle.resume_time());
+
+    assert_eq!(Ok(true), handle.pause_time());
+    assert_eq!(Ok(true), handle.resume_time());
+    assert_eq!(Ok(false), handle.resume_time());
+    assert_eq!(0, handle.pause_depth());
+}
+
+#[test]
+fn runtimes_share_a_local_clock() {
+    use tokio::time::LocalClock;
+
+    let clock = LocalClock::new(false);
+
+    let build = || {
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .clock(clock.clone())
+            .build()
+            .unwrap()
+    };
+
+    let first = build();
+    let second = build();
+
+    assert_eq!(Ok(true), first.handle().pause_time());
+    assert!(clock.is_paused());
+    assert!(second.handle().is_time_paused());
+
+    let frozen = clock.now();
+    assert_eq!(
+        frozen,
+        first.block_on(async { tokio::time::Instant::now() })
+    );
+    assert_eq!(
+        frozen,
+        second.block_on(async { tokio::time::Instant::now() })
+    );
+    assert_eq!(clock.elapsed(), clock.elapsed());
+
+    assert!(clock.resume());
+    assert!(!first.handle().is_time_paused());
+    assert!(!second.handle().is_time_paused());
+
+    // The clock outlives the runtimes built with it.
+    drop(first);
+    drop(second);
+    assert!(clock.pause());
+}
+
+#[test]
+fn local_clock_cannot_be_configured_by_the_builder() {
+    let err = runtime::Builder::new_current_thread()
+        .enable_all()
+        .clock(tokio::time::LocalClock::new(false))
+        .start_paused(true)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn try_advance_requires_a_paused_clock() {
+    use tokio::time::{try_advance, Instant};
+
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        assert_eq!(
+            Err(ClockError::NotPaused),
+            try_advance(Duration::from_secs(1)).await
+        );
+
+        tokio::time::try_pause().unwrap();
+        let frozen = Instant::now();
+
+        assert_eq!(Ok(()), try_advance(Duration::from_secs(1)).await);
+        assert_eq!(frozen + Duration::from_secs(1), Instant::now());
+        assert!(tokio::time::is_paused());
+    });
+}
+
+#[test]
+fn elapsed_does_not_grow_while_paused() {
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    let start = rt.block_on(async { Instant::now() });
+    handle.pause_time().unwrap();
+
+    let frozen = rt.block_on(async { start.elapsed() });
+    std::thread::sleep(Duration::from_millis(30));
+
+    rt.block_on(async {
+        assert_eq!(frozen, start.elapsed());
+        assert_eq!(frozen, Instant::now().duration_since(start));
+    });
+
+    handle.resume_time().unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+
+    rt.block_on(async {
+        assert!(start.elapsed() >= frozen + Duration::from_millis(10));
+    });
+}
+
+#[test]
+fn logical_nanos_freezes_while_paused_and_never_decreases() {
+    use tokio::time::{logical_nanos, try_advance};
+
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    let before = rt.block_on(async { logical_nanos() });
+    handle.pause_time().unwrap();
+
+    let frozen = rt.block_on(async { logical_nanos() });
+    assert!(frozen >= before);
+    std::thread::sleep(Duration::from_millis(20));
+
+    rt.block_on(async {
+        assert_eq!(frozen, logical_nanos());
+
+        // Advances are counted at full resolution.
+        try_advance(Duration::from_nanos(1_500)).await.unwrap();
+        assert_eq!(frozen + 1_500, logical_nanos());
+    });
+
+    handle.resume_time().unwrap();
+
+    rt.block_on(async {
+        let mut last = logical_nanos();
+        assert!(last >= frozen + 1_500);
+
+        for _ in 0..1000 {
+            let now = logical_nanos();
+            assert!(now >= last);
+            last = now;
+        }
+    });
+}
+
+#[test]
+fn pause_and_snapshot_captures_the_frozen_instant() {
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    let snapshot = handle.pause_and_snapshot().unwrap();
+    assert!(snapshot.is_paused());
+    assert_eq!(1, handle.pause_depth());
+
+    std::thread::sleep(Duration::from_millis(20));
+
+    rt.block_on(async {
+        assert_eq!(snapshot.now(), Instant::now());
+        assert_eq!(
+            snapshot.elapsed_millis(),
+            tokio::time::clock_snapshot().elapsed_millis()
+        );
+    });
+
+    // A nested call reports the same frozen state.
+    let nested = handle.pause_and_snapshot().unwrap();
+    assert_eq!(snapshot.now(), nested.now());
+    assert_eq!(2, handle.pause_depth());
+
+    assert_eq!(Ok(false), handle.resume_time());
+    assert_eq!(Ok(true), handle.resume_time());
+}
+
+#[test]
+fn now_std_follows_the_runtime_clock() {
+    use tokio::time::{now_std, Instant};
+
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+
+    rt.block_on(async {
+        let frozen = now_std();
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(frozen, now_std());
+        assert_eq!(frozen, Instant::now().into_std());
+    });
+
+    // Outside of a runtime, the system clock is used.
+    let before = std::time::Instant::now();
+    let now = now_std();
+    assert!(before <= now && now <= std::time::Instant::now());
+}
+
+#[test]
+fn duration_until_stands_still_while_paused() {
+    use tokio::time::{duration_until, try_advance, Instant};
+
+    let rt = pausable_rt();
+
+    let deadline = rt.block_on(async { Instant::now() + Duration::from_secs(10) });
+    rt.handle().pause_time().unwrap();
+
+    rt.block_on(async move {
+        let remaining = duration_until(deadline);
+        assert!(remaining <= Duration::from_secs(10));
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(remaining, duration_until(deadline));
+
+        try_advance(Duration::from_secs(4)).await.unwrap();
+        assert_eq!(remaining - Duration::from_secs(4), duration_until(deadline));
+
+        // Saturates instead of underflowing once the deadline has passed.
+        try_advance(Duration::from_secs(60)).await.unwrap();
+        assert_eq!(Duration::from_secs(0), duration_until(deadline));
+    });
+
+    rt.handle().resume_time().unwrap();
+
+    rt.block_on(async move {
+        let near = Instant::now() + Duration::from_millis(50);
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(duration_until(near) <= Duration::from_millis(30));
+
+        assert_eq!(Duration::from_secs(0), duration_until(deadline));
+    });
+}
+
+#[test]
+fn last_pause_real_instant_marks_the_start_of_the_current_pause() {
+    use tokio::time::last_pause_real_instant;
+
+    let last_pause = |rt: &Runtime| rt.block_on(async { last_pause_real_instant() });
+
+    let rt = pausable_rt();
+    let handle = rt.handle();
+    assert_eq!(None, last_pause(&rt));
+
+    let before = std::time::Instant::now();
+    handle.pause_time().unwrap();
+    let after = std::time::Instant::now();
+
+    let since = last_pause(&rt).unwrap();
+    assert!(before <= since && since <= after);
+
+    // A nested pause does not restart the freeze.
+    std::thread::sleep(Duration::from_millis(10));
+    handle.pause_time().unwrap();
+    assert_eq!(Some(since), last_pause(&rt));
+
+    handle.resume_time().unwrap();
+    assert_eq!(Some(since), last_pause(&rt));
+
+    handle.resume_time().unwrap();
+    assert_eq!(None, last_pause(&rt));
+
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    assert_eq!(None, last_pause(&rt));
+}
+
+#[test]
+fn system_now_freezes_and_advances_with_the_runtime_clock() {
+    use tokio::time::{system_now, try_advance};
+
+    let rt = pausable_rt();
+    rt.handle().pause_time().unwrap();
+
+    rt.block_on(async {
+        let frozen = system_now();
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(frozen, system_now());
+
+        try_advance(Duration::from_secs(60)).await.unwrap();
+        assert_eq!(frozen + Duration::from_secs(60), system_now());
+    });
+
+    rt.handle().resume_time().unwrap();
+
+    rt.block_on(async {
+        let before = system_now();
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(system_now() - Duration::from_millis(20) >= before);
+    });
+
+    // Outside of a runtime, the system clock is used.
+    let before = std::time::SystemTime::now();
+    let now = system_now();
+    assert!(before <= now && now <= std::time::SystemTime::now());
+}
+
+#[test]
+fn resume_with_advance_skips_ahead_and_resumes() {
+    use tokio::time::Instant;
+
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    assert_eq!(
+        Err(ClockError::NotPaused),
+        handle.resume_with_advance(Duration::from_secs(1))
+    );
+
+    handle.pause_time().unwrap();
+    handle.pause_time().unwrap();
+    let frozen = rt.block_on(async { Instant::now() });
+
+    // The outer pause keeps the clock frozen, but the advance applies.
+    assert_eq!(
+        Ok(false),
+        handle.resume_with_advance(Duration::from_secs(1))
+    );
+    assert!(handle.is_time_paused());
+    assert_eq!(
+        frozen + Duration::from_secs(1),
+        rt.block_on(async { Instant::now() })
+    );
+
+    assert_eq!(Ok(true), handle.resume_with_advance(Duration::from_secs(1)));
+    assert!(!handle.is_time_paused());
+    assert!(rt.block_on(async { Instant::now() }) >= frozen + Duration::from_secs(2));
+}
+
+#[test]
+fn build_pausable_leaves_the_builder_unchanged() {
+    let mut builder = runtime::Builder::new_current_thread();
+    builder.enable_all();
+
+    let rt = builder.build_pausable().unwrap();
+    assert!(rt.handle().pause());
+    assert!(builder.time_config().is_none());
+}
+
+#[test]
+fn build_pausable_gives_an_infallible_pause() {
+    let rt = runtime::Builder::new_current_thread()
+        .enable_all()
+        .build_pausable()
+        .unwrap();
+
+    let handle = rt.handle().clone();
+    assert!(handle.pause());
+    assert!(!handle.pause());
+    assert_eq!(2, handle.pause_depth());
+
+    rt.block_on(async {
+        assert!(tokio::time::is_paused());
+    });
+
+    let frozen = rt.block_on(async { tokio::time::Instant::now() });
+    handle.advance(Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        frozen + Duration::from_secs(1),
+        rt.block_on(async { tokio::time::Instant::now() })
+    );
+
+    assert!(!handle.resume());
+    assert!(handle.resume());
+    assert!(!handle.is_paused());
+    assert_eq!(
+        Err(ClockError::NotPaused),
+        handle.advance(Duration::from_secs(1))
+    );
+
+    // The underlying runtime sees the same clock.
+    assert!(handle.pause());
+    assert!(rt.runtime().handle().is_time_paused());
+    assert!(rt.into_runtime().handle().is_time_paused());
+}
+
+#[test]
+fn handle_pausable_is_some_for_pausable_runtimes() {
+    let rt = pausable_rt();
+    let handle = rt.handle().pausable().unwrap();
+
+    assert!(handle.pause());
+    assert!(rt.handle().is_time_paused());
+    assert!(handle.resume());
+}
+
+#[test]
+fn assert_paused_and_resumed_follow_the_clock() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        tokio::time::assert_resumed();
+
+        tokio::time::try_pause().unwrap();
+        tokio::time::assert_paused();
+
+        tokio::time::try_resume().unwrap();
+        tokio::time::assert_resumed();
+    });
+}
+
+#[test]
+#[should_panic(expected = "expected the runtime's clock to be paused, but it is running at")]
+fn assert_paused_panics_while_running() {
+    let rt = pausable_rt();
+
+    rt.block_on(async { tokio::time::assert_paused() });
+}
+
+#[test]
+#[should_panic(expected = "expected the runtime's clock to be running, but it is paused at")]
+fn assert_resumed_panics_while_paused() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+        tokio::time::assert_resumed();
+    });
+}
+
+#[test]
+fn sleep_remaining_stops_decreasing_while_paused() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+        let sleep = tokio::time::sleep(Duration::from_secs(10));
+        assert_eq!(Duration::from_secs(10), sleep.remaining());
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert_eq!(Duration::from_secs(10), sleep.remaining());
+
+        tokio::time::try_resume().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let remaining = sleep.remaining();
+        assert!(remaining < Duration::from_secs(10));
+
+        tokio::time::try_pause().unwrap();
+        let frozen = sleep.remaining();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert_eq!(frozen, sleep.remaining());
+        assert!(frozen <= remaining);
+    });
+}
+
+#[test]
+fn sleep_remaining_is_zero_once_elapsed() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        let sleep = tokio::time::sleep(Duration::from_millis(1));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        assert_eq!(Duration::from_secs(0), sleep.remaining());
+    });
+}
+
+#[test]
+fn advance_to_sets_the_frozen_instant() {
+    let rt = pausable_rt();
+    let handle = rt.handle();
+
+    handle.pause_time().unwrap();
+    let start = rt.block_on(async { tokio::time::Instant::now() });
+
+    handle.advance_to(start).unwrap();
+    handle.advance_to(start + Duration::from_secs(3)).unwrap();
+    assert_eq!(
+        start + Duration::from_secs(3),
+        rt.block_on(async { tokio::time::Instant::now() })
+    );
+
+    assert_eq!(Err(ClockError::InPast), handle.advance_to(start));
+}
+
+#[test]
+fn frozen_pauses_while_the_future_runs() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        let output = tokio::time::frozen(async {
+            assert!(tokio::time::is_paused());
+            tokio::task::yield_now().await;
+            assert!(tokio::time::is_paused());
+            42
+        })
+        .await;
+
+        assert_eq!(42, output);
+        assert!(!tokio::time::is_paused());
+    });
+}
+
+#[test]
+fn frozen_keeps_an_outer_pause() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        tokio::time::try_pause().unwrap();
+        tokio::time::frozen(async {}).await;
+        assert!(tokio::time::is_paused());
+    });
+}
+
+#[test]
+fn frozen_resumes_when_cancelled() {
+    use tokio_test::{assert_pending, task};
+
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let mut frozen = task::spawn(tokio::time::frozen(rx));
+
+        // The clock is paused on the first poll.
+        assert!(!tokio::time::is_paused());
+        assert_pending!(frozen.poll());
+        assert!(tokio::time::is_paused());
+
+        drop(frozen);
+        assert!(!tokio::time::is_paused());
+    });
+}
+
+#[test]
+fn frozen_resumes_when_the_future_panics() {
+    let rt = pausable_rt();
+
+    rt.block_on(async {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use tokio_test::task;
+
+        let mut frozen = task::spawn(tokio::time::frozen(async { panic!("boom") }));
+        let result = catch_unwind(AssertUnwindSafe(|| frozen.poll()));
+
+        assert!(result.is_err());
+        assert!(!tokio::time::is_paused());
+    });
+}